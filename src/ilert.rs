@@ -1,112 +1,866 @@
+#[cfg(feature = "reqwest")]
 use reqwest::blocking::Client;
+#[cfg(feature = "reqwest")]
 use reqwest::redirect::Policy;
-use reqwest::ClientBuilder;
-use reqwest::header;
+#[cfg(feature = "reqwest")]
+use reqwest::{Certificate, Identity};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
+use serde_derive::Deserialize;
 use log::{debug};
 
+use crate::ilert_auth::{AuthProvider, BasicAuth, CallbackTokenAuth, FileTokenAuth, TokenAuth};
 use crate::ilert_builders::{DeleteRequestBuilder, GetRequestBuilder, PostRequestBuilder, PutRequestBuilder};
 use crate::ilert_error::{ILertResult, ILertError};
-use reqwest::header::{HeaderMap, HeaderValue};
+use crate::ilert_cassette::{Cassette, CassetteTransport};
+use crate::ilert_metrics::MetricsHook;
+use crate::ilert_rate_limit::{RateLimitConfig, RateLimiter};
+use crate::ilert_redact::{redact_url, REDACTED};
+use crate::ilert_transport::HttpTransport;
+#[cfg(feature = "reqwest")]
+use crate::ilert_transport::ReqwestTransport;
+use http::header::{HeaderMap, HeaderValue};
 use std::error::Error;
 
 #[derive(Debug, Clone)]
 pub struct ILert {
     host: String,
     api_ep: String,
-    pub api_token: Option<String>,
-    pub auth_user: Option<String>,
-    pub auth_psw: Option<String>,
-    pub http_client: Client,
+    auth: Option<Arc<dyn AuthProvider>>,
+    metrics_hook: Option<Arc<dyn MetricsHook>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    transport: Arc<dyn HttpTransport>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ILertRegion {
+    EU,
+    US,
+}
+
+impl ILertRegion {
+
+    pub fn host(&self) -> &str {
+        match self {
+            ILertRegion::EU => "https://api.ilert.com",
+            ILertRegion::US => "https://api.us.ilert.com",
+        }
+    }
+}
+
+impl FromStr for ILertRegion {
+    type Err = ILertError;
+
+    fn from_str(val: &str) -> ILertResult<ILertRegion> {
+        match val {
+            "EU" => Ok(ILertRegion::EU),
+            "US" => Ok(ILertRegion::US),
+            _ => Err(ILertError::new(format!("Unsupported region '{}', expected EU or US.", val).as_str())),
+        }
+    }
+}
+
+/// Authentication of a profile, secrets may be given inline or read from the named environment variable.
+#[derive(Clone, Deserialize)]
+#[serde(tag = "mode", rename_all = "lowercase")]
+pub enum ILertAuthConfig {
+    Token {
+        token: Option<String>,
+        token_env: Option<String>,
+        token_file: Option<String>,
+    },
+    Basic {
+        user: String,
+        password: Option<String>,
+        password_env: Option<String>,
+    },
+    None,
+}
+
+impl fmt::Debug for ILertAuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted = |secret: &Option<String>| secret.as_ref().map(|_| REDACTED);
+        match self {
+            ILertAuthConfig::Token { token, token_env, token_file } => f.debug_struct("Token")
+                .field("token", &redacted(token))
+                .field("token_env", token_env)
+                .field("token_file", token_file)
+                .finish(),
+            ILertAuthConfig::Basic { user, password, password_env } => f.debug_struct("Basic")
+                .field("user", user)
+                .field("password", &redacted(password))
+                .field("password_env", password_env)
+                .finish(),
+            ILertAuthConfig::None => write!(f, "None"),
+        }
+    }
+}
+
+/// Settings of a single named profile, unset values fall back to the `ILertBuilder` defaults.
+#[derive(Clone, Default, Deserialize)]
+pub struct ILertProfile {
+    pub host: Option<String>,
+    pub region: Option<String>,
+    pub base_path: Option<String>,
+    pub timeout_sec: Option<u64>,
+    pub connect_timeout_sec: Option<u64>,
+    pub user_agent_suffix: Option<String>,
+    pub proxy: Option<String>,
+    pub auth: Option<ILertAuthConfig>,
+}
+
+impl fmt::Debug for ILertProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ILertProfile")
+            .field("host", &self.host)
+            .field("region", &self.region)
+            .field("base_path", &self.base_path)
+            .field("timeout_sec", &self.timeout_sec)
+            .field("connect_timeout_sec", &self.connect_timeout_sec)
+            .field("user_agent_suffix", &self.user_agent_suffix)
+            .field("proxy", &self.proxy.as_deref().map(redact_url))
+            .field("auth", &self.auth)
+            .finish()
+    }
+}
+
+/// Config file with named profiles, e.g. in TOML:
+///
+/// ```toml
+/// default_profile = "prod"
+///
+/// [profiles.prod]
+/// region = "EU"
+/// auth = { mode = "token", token_file = "/var/run/secrets/ilert/token" }
+///
+/// [profiles.local]
+/// host = "http://localhost:8080"
+/// timeout_sec = 5
+/// auth = { mode = "basic", user = "chris@chris", password = "chris" }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ILertConfig {
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: HashMap<String, ILertProfile>,
+}
+
+fn read_secret(inline: &Option<String>, env_name: &Option<String>, what: &str,
+               lookup: &dyn Fn(&str) -> Option<String>) -> ILertResult<String> {
+
+    if let Some(value) = inline {
+        return Ok(value.clone());
+    }
+
+    match env_name {
+        Some(env_name) => lookup(env_name.as_str())
+            .ok_or_else(|| ILertError::new(format!("Missing {}, environment variable {} is not set.", what, env_name).as_str())),
+        None => Err(ILertError::new(format!("Missing {}, set it inline or via its _env variable.", what).as_str())),
+    }
+}
+
+impl ILertConfig {
+
+    /// Parses TOML, or JSON if the content starts with `{`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(content: &str) -> ILertResult<ILertConfig> {
+        if content.trim_start().starts_with('{') {
+            serde_json::from_str(content)
+                .map_err(|err| ILertError::new(format!("Invalid JSON config: {}.", err).as_str()))
+        } else {
+            toml::from_str(content)
+                .map_err(|err| ILertError::new(format!("Invalid TOML config: {}.", err).as_str()))
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ILertResult<ILertConfig> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| ILertError::new(format!("Failed to read config file '{}': {}.", path.display(), err).as_str()))?;
+        ILertConfig::from_str(content.as_str())
+    }
+
+    /// Returns the named profile, or the `default_profile` if `name` is `None`.
+    pub fn profile(&self, name: Option<&str>) -> ILertResult<&ILertProfile> {
+
+        let name = match name.or(self.default_profile.as_deref()) {
+            Some(name) => name,
+            None => return Err(ILertError::new("No profile given and config has no default_profile.")),
+        };
+
+        self.profiles.get(name)
+            .ok_or_else(|| ILertError::new(format!("Profile '{}' not found in config.", name).as_str()))
+    }
+}
+
+impl ILertProfile {
+
+    pub fn to_builder(&self) -> ILertResult<ILertBuilder> {
+
+        let mut builder = ILertBuilder::new();
+
+        if let Some(region) = &self.region {
+            builder = builder.region(region.parse::<ILertRegion>()?);
+        }
+
+        if let Some(host) = &self.host {
+            builder = builder.host(host.as_str());
+        }
+
+        if let Some(base_path) = &self.base_path {
+            builder = builder.base_path(base_path.as_str());
+        }
+
+        if let Some(timeout_sec) = self.timeout_sec {
+            builder = builder.timeout_sec(timeout_sec);
+        }
+
+        if let Some(connect_timeout_sec) = self.connect_timeout_sec {
+            builder = builder.connect_timeout(Duration::from_secs(connect_timeout_sec));
+        }
+
+        if let Some(user_agent_suffix) = &self.user_agent_suffix {
+            builder = builder.user_agent_suffix(user_agent_suffix.as_str());
+        }
+
+        if let Some(proxy) = &self.proxy {
+            #[cfg(feature = "reqwest")]
+            {
+                builder = builder.proxy(proxy.as_str());
+            }
+            #[cfg(not(feature = "reqwest"))]
+            return Err(ILertError::new(format!("Proxy '{}' requires the reqwest feature.", redact_url(proxy.as_str())).as_str()));
+        }
+
+        Ok(builder)
+    }
+
+    pub fn to_client(&self) -> ILertResult<ILert> {
+        self.to_client_with_env(&|name| std::env::var(name).ok())
+    }
+
+    pub(crate) fn to_client_with_env(&self, lookup: &dyn Fn(&str) -> Option<String>) -> ILertResult<ILert> {
+
+        let mut client = self.to_builder()?.build()?;
+
+        match &self.auth {
+            Some(ILertAuthConfig::Token { token_file: Some(token_file), .. }) => {
+                client.auth_via_token_file(token_file)?;
+            },
+            Some(ILertAuthConfig::Token { token, token_env, .. }) => {
+                let token = read_secret(token, token_env, "api token", lookup)?;
+                client.auth_via_token(token.as_str())?;
+            },
+            Some(ILertAuthConfig::Basic { user, password, password_env }) => {
+                let password = read_secret(password, password_env, "password", lookup)?;
+                client.auth_via_user(user.as_str(), password.as_str())?;
+            },
+            Some(ILertAuthConfig::None) | None => (),
+        };
+
+        Ok(client)
+    }
+}
+
+/// Proxy settings of the built-in `reqwest` client, requires the (default) `reqwest` feature.
+#[cfg(feature = "reqwest")]
+#[derive(Clone)]
+pub struct ProxyConfig {
+    url: String,
+    auth: Option<(String, String)>,
+    no_proxy: Option<String>,
+}
+
+#[cfg(feature = "reqwest")]
+impl ProxyConfig {
+
+    /// Proxy url used for http and https requests, e.g. `http://proxy.corp:3128`.
+    pub fn new(url: &str) -> ProxyConfig {
+        ProxyConfig {
+            url: url.to_string(),
+            auth: None,
+            no_proxy: None
+        }
+    }
+
+    pub fn basic_auth(mut self, user: &str, psw: &str) -> Self {
+        self.auth = Some((user.to_string(), psw.to_string()));
+        self
+    }
+
+    /// Comma separated hosts, domains or ip ranges that bypass the proxy, e.g. `localhost,.corp`.
+    pub fn no_proxy(mut self, no_proxy: &str) -> Self {
+        self.no_proxy = Some(no_proxy.to_string());
+        self
+    }
+
+    fn to_proxy(&self) -> ILertResult<reqwest::Proxy> {
+
+        let mut proxy = reqwest::Proxy::all(self.url.as_str())
+            .map_err(|err| ILertError::new(format!("Invalid proxy '{}': {}.", redact_url(self.url.as_str()), err).as_str()))?;
+
+        if let Some((user, psw)) = &self.auth {
+            proxy = proxy.basic_auth(user.as_str(), psw.as_str());
+        }
+
+        if let Some(no_proxy) = &self.no_proxy {
+            proxy = proxy.no_proxy(reqwest::NoProxy::from_string(no_proxy.as_str()));
+        }
+
+        Ok(proxy)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &redact_url(self.url.as_str()))
+            .field("auth", &self.auth.as_ref().map(|(user, _)| (user, REDACTED)))
+            .field("no_proxy", &self.no_proxy)
+            .finish()
+    }
+}
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(25);
+
+#[derive(Debug, Clone)]
+pub struct ILertBuilder {
+    host: String,
+    base_path: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent_suffix: Option<String>,
+    https_only: bool,
+    danger_accept_invalid_certs: bool,
+    #[cfg(feature = "reqwest")]
+    tls_built_in_root_certs: bool,
+    #[cfg(feature = "reqwest")]
+    proxy: Option<ProxyConfig>,
+    #[cfg(feature = "reqwest")]
+    root_certificates: Vec<Certificate>,
+    #[cfg(feature = "reqwest")]
+    identity: Option<Identity>,
+    #[cfg(feature = "reqwest")]
+    http_client: Option<Client>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    metrics_hook: Option<Arc<dyn MetricsHook>>,
+    rate_limit: Option<RateLimitConfig>,
+    cassette: Option<Arc<Cassette>>,
+    error: Option<String>,
+}
+
+impl Default for ILertBuilder {
+    fn default() -> Self {
+        ILertBuilder::new()
+    }
+}
+
+impl ILertBuilder {
+
+    pub fn new() -> ILertBuilder {
+        ILertBuilder {
+            host: ILertRegion::EU.host().to_string(),
+            base_path: "/api".to_string(),
+            timeout: None,
+            connect_timeout: None,
+            user_agent_suffix: None,
+            https_only: false,
+            danger_accept_invalid_certs: false,
+            #[cfg(feature = "reqwest")]
+            tls_built_in_root_certs: true,
+            #[cfg(feature = "reqwest")]
+            proxy: None,
+            #[cfg(feature = "reqwest")]
+            root_certificates: Vec::new(),
+            #[cfg(feature = "reqwest")]
+            identity: None,
+            #[cfg(feature = "reqwest")]
+            http_client: None,
+            transport: None,
+            metrics_hook: None,
+            rate_limit: None,
+            cassette: None,
+            error: None,
+        }
+    }
+
+    /// Scheme and authority only, e.g. `http://localhost:8080`, see `base_path()` for prefixes.
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = host.to_string();
+        self
+    }
+
+    pub fn region(mut self, region: ILertRegion) -> Self {
+        self.host = region.host().to_string();
+        self
+    }
+
+    /// Path the api is mounted under, defaults to `/api`.
+    pub fn base_path(mut self, base_path: &str) -> Self {
+        self.base_path = base_path.to_string();
+        self
+    }
+
+    /// Total time for a request incl. connecting and reading the response body, defaults to 25s.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn timeout_sec(self, timeout_sec: u64) -> Self {
+        self.timeout(Duration::from_secs(timeout_sec))
+    }
+
+    /// Time to establish a connection, bounded by `timeout()` if not set.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Appended to the `ilert-rust/<version>` user agent, e.g. `deploy-tool/1.2`.
+    pub fn user_agent_suffix(mut self, suffix: &str) -> Self {
+        self.user_agent_suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Proxy url used for all requests, e.g. `http://proxy.corp:3128`.
+    #[cfg(feature = "reqwest")]
+    pub fn proxy(mut self, proxy_url: &str) -> Self {
+        self.proxy = Some(ProxyConfig::new(proxy_url));
+        self
+    }
+
+    #[cfg(feature = "reqwest")]
+    pub fn proxy_config(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn https_only(mut self, https_only: bool) -> Self {
+        self.https_only = https_only;
+        self
+    }
+
+    /// Disables certificate validation, only meant for local mocks with self signed certificates.
+    pub fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.danger_accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Trusts the given certificate in addition to the built-in roots, e.g. a corporate CA.
+    #[cfg(feature = "reqwest")]
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Trusts all certificates of the given PEM bundle, parse errors are returned by `build()`.
+    #[cfg(feature = "reqwest")]
+    pub fn add_root_certificates_pem(mut self, pem_bundle: &[u8]) -> Self {
+        match Certificate::from_pem_bundle(pem_bundle) {
+            Ok(certificates) if certificates.is_empty() => self.error = Some("Invalid root certificate bundle: no certificates found.".to_string()),
+            Ok(certificates) => self.root_certificates.extend(certificates),
+            Err(err) => self.error = Some(format!("Invalid root certificate bundle: {}.", err)),
+        }
+        self
+    }
+
+    /// Set to `false` to only trust certificates added via `add_root_certificate()`.
+    #[cfg(feature = "reqwest")]
+    pub fn tls_built_in_root_certs(mut self, enabled: bool) -> Self {
+        self.tls_built_in_root_certs = enabled;
+        self
+    }
+
+    /// Client certificate presented to hosts that require mutual TLS.
+    #[cfg(feature = "reqwest")]
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Client certificate from PEM encoded certificate chain and PKCS#8 key, parse errors are returned by `build()`.
+    #[cfg(feature = "reqwest")]
+    pub fn identity_pem(mut self, certificate_pem: &[u8], key_pem: &[u8]) -> Self {
+        match Identity::from_pkcs8_pem(certificate_pem, key_pem) {
+            Ok(identity) => self.identity = Some(identity),
+            Err(err) => self.error = Some(format!("Invalid client identity: {}.", err)),
+        }
+        self
+    }
+
+    /// Uses the given client (e.g. to share its connection pool) instead of building one,
+    /// `build()` fails if timeout, proxy, TLS or user agent options are set as well.
+    /// The `Accept` and `Content-Type` headers are still set per request.
+    #[cfg(feature = "reqwest")]
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sends all requests through the given transport instead of a `reqwest` client built by this builder,
+    /// `build()` fails if timeout, proxy, TLS or user agent options are set as well.
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Hook called after every request attempt, e.g. `InMemoryMetrics` or an adapter to your metrics library.
+    pub fn metrics_hook(mut self, metrics_hook: Arc<dyn MetricsHook>) -> Self {
+        self.metrics_hook = Some(metrics_hook);
+        self
+    }
+
+    /// Client-side token bucket rate limiting with separate budgets for event and management requests,
+    /// clones of the built client share the same budgets.
+    pub fn rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limit = Some(config);
+        self
+    }
+
+    /// Records requests to or replays responses from the given cassette, see `ilert_cassette`.
+    pub fn cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    fn validate_host(&self) -> ILertResult<String> {
+
+        let url = url::Url::parse(self.host.as_str())
+            .map_err(|err| ILertError::new(format!("Invalid host '{}': {}.", self.host, err).as_str()))?;
+
+        if url.scheme() != "http" && url.scheme() != "https" {
+            return Err(ILertError::new(format!("Invalid host '{}': scheme must be http or https.", self.host).as_str()));
+        }
+
+        if self.https_only && url.scheme() != "https" {
+            return Err(ILertError::new(format!("Invalid host '{}': https is required.", self.host).as_str()));
+        }
+
+        if url.host_str().is_none() || url.path() != "/" || url.query().is_some() || url.fragment().is_some() {
+            return Err(ILertError::new(format!("Invalid host '{}': expected scheme and authority only, use base_path() for prefixes.", self.host).as_str()));
+        }
+
+        Ok(self.host.trim_end_matches('/').to_string())
+    }
+
+    fn validate_base_path(&self) -> ILertResult<String> {
+
+        let base_path = self.base_path.trim_end_matches('/');
+
+        if base_path.is_empty() {
+            return Ok(String::new());
+        }
+
+        if !base_path.starts_with('/') {
+            return Err(ILertError::new(format!("Invalid base path '{}': must start with '/'.", self.base_path).as_str()));
+        }
+
+        if base_path.contains(['?', '#']) {
+            return Err(ILertError::new(format!("Invalid base path '{}': must not contain '?' or '#'.", self.base_path).as_str()));
+        }
+
+        Ok(base_path.to_string())
+    }
+
+    fn user_agent(&self) -> String {
+        match &self.user_agent_suffix {
+            Some(suffix) => format!("ilert-rust/{} {}", env!("CARGO_PKG_VERSION"), suffix),
+            None => format!("ilert-rust/{}", env!("CARGO_PKG_VERSION")),
+        }
+    }
+
+    /// Options that only apply to the client built by this builder.
+    fn client_options(&self) -> Vec<&str> {
+
+        let mut options = Vec::new();
+        if self.timeout.is_some() {
+            options.push("timeout");
+        }
+        if self.connect_timeout.is_some() {
+            options.push("connect_timeout");
+        }
+        if self.user_agent_suffix.is_some() {
+            options.push("user_agent_suffix");
+        }
+        if self.danger_accept_invalid_certs {
+            options.push("danger_accept_invalid_certs");
+        }
+
+        #[cfg(feature = "reqwest")]
+        {
+            if !self.tls_built_in_root_certs {
+                options.push("tls_built_in_root_certs");
+            }
+            if self.proxy.is_some() {
+                options.push("proxy");
+            }
+            if !self.root_certificates.is_empty() {
+                options.push("root certificates");
+            }
+            if self.identity.is_some() {
+                options.push("identity");
+            }
+        }
+
+        options
+    }
+
+    #[cfg(feature = "reqwest")]
+    fn build_http_client(&self) -> ILertResult<Client> {
+
+        let user_agent = HeaderValue::from_str(self.user_agent().as_str())
+            .map_err(|err| ILertError::new(format!("Invalid user agent suffix: {}.", err).as_str()))?;
+
+        let mut headers = ILert::get_default_headers();
+        headers.insert("User-Agent", user_agent);
+
+        let mut client_builder = reqwest::blocking::Client::builder()
+            .timeout(self.timeout.unwrap_or(DEFAULT_TIMEOUT))
+            .redirect(Policy::none())
+            .default_headers(headers)
+            .https_only(self.https_only)
+            .danger_accept_invalid_certs(self.danger_accept_invalid_certs)
+            .tls_built_in_root_certs(self.tls_built_in_root_certs);
+
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(proxy) = &self.proxy {
+            client_builder = client_builder.proxy(proxy.to_proxy()?);
+        }
+
+        for certificate in &self.root_certificates {
+            client_builder = client_builder.add_root_certificate(certificate.clone());
+        }
+
+        if let Some(identity) = &self.identity {
+            client_builder = client_builder.identity(identity.clone());
+        }
+
+        client_builder.build()
+            .map_err(|err| ILertError::new(err.to_string().as_str()))
+    }
+
+    #[cfg(feature = "reqwest")]
+    fn default_transport(&self) -> ILertResult<Arc<dyn HttpTransport>> {
+        match &self.http_client {
+            Some(http_client) => Ok(Arc::new(ReqwestTransport::new(http_client.clone()))),
+            None => Ok(Arc::new(ReqwestTransport::new(self.build_http_client()?))),
+        }
+    }
+
+    #[cfg(not(feature = "reqwest"))]
+    fn default_transport(&self) -> ILertResult<Arc<dyn HttpTransport>> {
+        Err(ILertError::new("No transport set, use transport() or enable the reqwest feature."))
+    }
+
+    fn build_transport(&self) -> ILertResult<Arc<dyn HttpTransport>> {
+
+        #[cfg(feature = "reqwest")]
+        if self.transport.is_some() && self.http_client.is_some() {
+            return Err(ILertError::new("Both http_client() and transport() are set, only one of them can be used."));
+        }
+
+        #[cfg(feature = "reqwest")]
+        let custom = self.transport.is_some() || self.http_client.is_some();
+        #[cfg(not(feature = "reqwest"))]
+        let custom = self.transport.is_some();
+
+        let options = self.client_options();
+        if custom && !options.is_empty() {
+            return Err(ILertError::new(format!("{} can not be applied to a custom http_client() or transport(), configure it there instead.",
+                options.join(", ")).as_str()));
+        }
+
+        match &self.transport {
+            Some(transport) => Ok(transport.clone()),
+            None => self.default_transport(),
+        }
+    }
+
+    pub fn build(self) -> ILertResult<ILert> {
+
+        if let Some(err) = &self.error {
+            return Err(ILertError::new(err.as_str()));
+        }
+
+        let host = self.validate_host()?;
+        let api_ep = self.validate_base_path()?;
+        let mut transport = self.build_transport()?;
+
+        if let Some(cassette) = &self.cassette {
+            transport = Arc::new(CassetteTransport::new(cassette.clone(), transport));
+        }
+
+        Ok(ILert {
+            host,
+            api_ep,
+            auth: None,
+            metrics_hook: self.metrics_hook.clone(),
+            rate_limiter: self.rate_limit.map(|config| {
+                let rate_limiter = RateLimiter::new(config);
+                match &self.metrics_hook {
+                    Some(metrics_hook) => Arc::new(rate_limiter.metrics_hook(metrics_hook.clone())),
+                    None => Arc::new(rate_limiter),
+                }
+            }),
+            transport,
+        })
+    }
 }
 
 impl ILert {
 
     pub fn new() -> ILertResult<ILert> {
-        let http_client_result = ILert::get_http_client(25);
-        match http_client_result {
-            Err(err) => Err(ILertError::new(err.to_string().as_str())),
-            Ok(http_client) => Ok(ILert {
-                host: "https://api.ilert.com".to_string(),
-                api_ep: "/api".to_string(),
-                api_token: None,
-                auth_user: None,
-                auth_psw: None,
-                http_client,
-            })
-        }
+        ILertBuilder::new().build()
     }
 
+    pub fn builder() -> ILertBuilder {
+        ILertBuilder::new()
+    }
+
+    /// Unlike `ILertBuilder::host()` the host is taken as is, incl. any path prefix.
+    #[deprecated(since="4.0.0", note="please use `ILert::builder()` instead")]
     pub fn new_with_opts(host: Option<&str>, timeout_sec: Option<u64>) -> ILertResult<ILert> {
-        let http_client_result = ILert::get_http_client(timeout_sec.unwrap_or(25));
-        match http_client_result {
-            Err(err) => Err(ILertError::new(err.to_string().as_str())),
-            Ok(http_client) => Ok(ILert {
-                host: host.unwrap_or("https://api.ilert.com").to_string(),
-                api_ep: "/api".to_string(),
-                api_token: None,
-                auth_user: None,
-                auth_psw: None,
-                http_client,
-            })
+
+        let mut client = ILertBuilder::new()
+            .timeout_sec(timeout_sec.unwrap_or(25))
+            .build()?;
+
+        if let Some(host) = host {
+            client.host = host.to_string();
         }
+
+        Ok(client)
+    }
+
+    /// Client configured from `ILERT_API_TOKEN` or `ILERT_USER` and `ILERT_PASSWORD` (one of them is required)
+    /// and the optional `ILERT_HOST`, `ILERT_REGION`, `ILERT_BASE_PATH`, `ILERT_TIMEOUT_SEC` and `ILERT_PROXY`.
+    pub fn from_env() -> ILertResult<ILert> {
+        ILert::from_env_with(&|name| std::env::var(name).ok())
+    }
+
+    pub(crate) fn from_env_with(lookup: &dyn Fn(&str) -> Option<String>) -> ILertResult<ILert> {
+
+        let timeout_sec = match lookup("ILERT_TIMEOUT_SEC") {
+            Some(raw) => Some(raw.trim().parse::<u64>()
+                .map_err(|_| ILertError::new(format!("Invalid ILERT_TIMEOUT_SEC '{}', expected seconds.", raw).as_str()))?),
+            None => None,
+        };
+
+        let auth = match (lookup("ILERT_API_TOKEN"), lookup("ILERT_USER"), lookup("ILERT_PASSWORD")) {
+            (Some(token), None, None) => ILertAuthConfig::Token { token: Some(token), token_env: None, token_file: None },
+            (None, Some(user), Some(password)) => ILertAuthConfig::Basic { user, password: Some(password), password_env: None },
+            (None, Some(_), None) => return Err(ILertError::new("ILERT_USER is set but ILERT_PASSWORD is missing.")),
+            (None, None, Some(_)) => return Err(ILertError::new("ILERT_PASSWORD is set but ILERT_USER is missing.")),
+            (Some(_), None, Some(_)) => return Err(ILertError::new("Both ILERT_API_TOKEN and ILERT_PASSWORD are set, ILERT_PASSWORD is only used with ILERT_USER.")),
+            (None, None, None) => return Err(ILertError::new("Missing credentials, set ILERT_API_TOKEN or ILERT_USER and ILERT_PASSWORD.")),
+            _ => return Err(ILertError::new("Both ILERT_API_TOKEN and ILERT_USER are set, only one auth mode is supported.")),
+        };
+
+        let profile = ILertProfile {
+            host: lookup("ILERT_HOST"),
+            region: lookup("ILERT_REGION"),
+            base_path: lookup("ILERT_BASE_PATH"),
+            timeout_sec,
+            proxy: lookup("ILERT_PROXY"),
+            auth: Some(auth),
+            ..ILertProfile::default()
+        };
+
+        profile.to_client_with_env(lookup)
+    }
+
+    /// Client configured from the given profile of a TOML or JSON config file, see `ILertConfig`.
+    pub fn from_config_file<P: AsRef<Path>>(path: P, profile: Option<&str>) -> ILertResult<ILert> {
+        ILertConfig::from_file(path)?
+            .profile(profile)?
+            .to_client()
     }
 
+    #[cfg(feature = "reqwest")]
     fn get_default_headers() -> HeaderMap {
         let mut headers = HeaderMap::new();
-        headers.append("User-Agent", HeaderValue::from_str("ilert-rust/3.2.0").unwrap());
         headers.append("Accept", HeaderValue::from_str("application/json").unwrap());
         headers.append("Content-Type", HeaderValue::from_str("application/json").unwrap());
         headers
     }
 
-    fn get_http_client(timeout_sec: u64) -> reqwest::Result<Client> {
+    // every auth_via_*() replaces the previously configured auth mode
 
-        let headers = ILert::get_default_headers();
-        let http_client_result = reqwest::blocking::Client::builder()
-            .timeout(Duration::from_secs(timeout_sec))
-            .redirect(Policy::none())
-            .default_headers(headers)
-            .build();
+    pub fn auth_via_token(&mut self, api_token: &str) -> ILertResult<&mut ILert> {
+        self.auth_via(TokenAuth::new(api_token))
+    }
 
-        http_client_result
+    pub fn auth_via_user(&mut self, auth_user: &str, auth_psw: &str) -> ILertResult<&mut ILert> {
+        self.auth_via(BasicAuth::new(auth_user, auth_psw))
     }
 
-    pub fn auth_via_token(&mut self, api_token: &str) -> ILertResult<&mut ILert> {
-        self.api_token = Some(api_token.to_string());
-        Ok(self)
+    pub fn auth_via_token_file<P: AsRef<Path>>(&mut self, path: P) -> ILertResult<&mut ILert> {
+        self.auth_via(FileTokenAuth::new(path))
     }
 
-    pub fn auth_via_user(&mut self, auth_user: &str, auth_psw: &str) -> ILertResult<&mut ILert> {
-        self.auth_user = Some(auth_user.to_string());
-        self.auth_psw = Some(auth_psw.to_string());
+    pub fn auth_via_callback<F>(&mut self, callback: F) -> ILertResult<&mut ILert> where F: Fn() -> ILertResult<String> + Send + Sync + 'static {
+        self.auth_via(CallbackTokenAuth::new(callback))
+    }
+
+    pub fn auth_via<A: AuthProvider + 'static>(&mut self, provider: A) -> ILertResult<&mut ILert> {
+        self.auth = Some(Arc::new(provider));
         Ok(self)
     }
 
+    pub fn clear_auth(&mut self) -> &mut ILert {
+        self.auth = None;
+        self
+    }
+
+    pub fn auth_provider(&self) -> Option<&dyn AuthProvider> {
+        self.auth.as_deref()
+    }
+
+    pub fn metrics_hook(&self) -> Option<&dyn MetricsHook> {
+        self.metrics_hook.as_deref()
+    }
+
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    pub fn transport(&self) -> &dyn HttpTransport {
+        self.transport.as_ref()
+    }
+
     pub fn build_url(&self, path: &str) -> String {
         let url = format!("{}{}{}", self.host.as_str(), self.api_ep.as_str(), path);
-        debug!("{}", url);
+        debug!("{}", redact_url(url.as_str()));
         url
     }
 
-    pub fn get(&self) -> GetRequestBuilder {
+    pub fn get(&self) -> GetRequestBuilder<'_> {
         GetRequestBuilder::new(self)
     }
 
     #[deprecated(since="3.0.0", note="please use `create()` instead")]
-    pub fn post(&self) -> PostRequestBuilder {
+    pub fn post(&self) -> PostRequestBuilder<'_> {
         PostRequestBuilder::new(self, "{}")
     }
 
-    pub fn create(&self) -> PostRequestBuilder {
+    pub fn create(&self) -> PostRequestBuilder<'_> {
         PostRequestBuilder::new(self, "{}")
     }
 
-    pub fn update(&self) -> PutRequestBuilder {
+    pub fn update(&self) -> PutRequestBuilder<'_> {
         PutRequestBuilder::new(self, "{}")
     }
 
-    pub fn delete(&self) -> DeleteRequestBuilder {
+    pub fn delete(&self) -> DeleteRequestBuilder<'_> {
         DeleteRequestBuilder::new(self)
     }
 }
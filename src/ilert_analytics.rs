@@ -0,0 +1,179 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Duration, DurationRound, Utc};
+
+use crate::ilert_builders::AlertState;
+use crate::ilert_models::{Alert, AlertLogEntry};
+use crate::ilert_reports::{acknowledgment_seconds, resolution_seconds, seconds_between};
+
+/// Nearest-rank percentiles in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percentiles {
+    pub count: usize,
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+    pub max: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceHourCount {
+    pub alert_source_id: Option<i64>,
+    pub hour: DateTime<Utc>,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlappingAlertKey {
+    pub alert_key: String,
+    pub transitions: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyAlertSource {
+    pub alert_source_id: Option<i64>,
+    pub name: Option<String>,
+    pub count: u64,
+}
+
+pub fn percentiles(values: &[f64]) -> Option<Percentiles> {
+
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let rank = |p: f64| {
+        let index = (p * sorted.len() as f64).ceil() as usize;
+        sorted[index.clamp(1, sorted.len()) - 1]
+    };
+
+    Some(Percentiles {
+        count: sorted.len(),
+        p50: rank(0.5),
+        p90: rank(0.9),
+        p99: rank(0.99),
+        max: sorted[sorted.len() - 1],
+    })
+}
+
+fn first_log_entry_time(log_entries: &[AlertLogEntry], alert_id: i64, matches: fn(&AlertLogEntry) -> bool) -> Option<DateTime<Utc>> {
+    log_entries.iter()
+        .filter(|entry| entry.alert_id == alert_id && matches(entry))
+        .map(|entry| entry.timestamp)
+        .min()
+}
+
+/// Seconds from report time until acceptance, falls back to the log entries if the alert carries no `accepted_on`.
+pub fn acknowledgment_times(alerts: &[Alert], log_entries: Option<&[AlertLogEntry]>) -> Vec<f64> {
+    alerts.iter()
+        .filter_map(|alert| acknowledgment_seconds(alert)
+            .or_else(|| log_entries
+                .and_then(|entries| first_log_entry_time(entries, alert.id, AlertLogEntry::is_accept))
+                .map(|at| seconds_between(&alert.report_time, &at))))
+        .collect()
+}
+
+/// Seconds from report time until resolution, falls back to the log entries if the alert carries no `resolved_on`.
+pub fn resolution_times(alerts: &[Alert], log_entries: Option<&[AlertLogEntry]>) -> Vec<f64> {
+    alerts.iter()
+        .filter_map(|alert| resolution_seconds(alert)
+            .or_else(|| log_entries
+                .and_then(|entries| first_log_entry_time(entries, alert.id, AlertLogEntry::is_resolve))
+                .map(|at| seconds_between(&alert.report_time, &at))))
+        .collect()
+}
+
+pub fn acknowledgment_percentiles(alerts: &[Alert], log_entries: Option<&[AlertLogEntry]>) -> Option<Percentiles> {
+    percentiles(&acknowledgment_times(alerts, log_entries))
+}
+
+pub fn resolution_percentiles(alerts: &[Alert], log_entries: Option<&[AlertLogEntry]>) -> Option<Percentiles> {
+    percentiles(&resolution_times(alerts, log_entries))
+}
+
+/// Alert counts per alert source and (UTC) hour of report time, ordered by source and hour.
+pub fn alerts_per_source_per_hour(alerts: &[Alert]) -> Vec<SourceHourCount> {
+
+    let mut counts: BTreeMap<(Option<i64>, DateTime<Utc>), u64> = BTreeMap::new();
+    for alert in alerts {
+        let hour = alert.report_time.duration_trunc(Duration::hours(1)).unwrap_or(alert.report_time);
+        *counts.entry((alert.alert_source.as_ref().map(|source| source.id), hour)).or_default() += 1;
+    }
+
+    counts.into_iter()
+        .map(|((alert_source_id, hour), count)| SourceHourCount {
+            alert_source_id,
+            hour,
+            count
+        })
+        .collect()
+}
+
+/// Alert keys that were resolved and reopened (a new alert reported after the previous one of
+/// the same key was resolved) at least `min_transitions` times within any span of `window`,
+/// ordered by transitions (highest first). Alerts that pile up while still open are not flapping.
+pub fn flapping_alert_keys(alerts: &[Alert], window: Duration, min_transitions: usize) -> Vec<FlappingAlertKey> {
+
+    let mut by_key: HashMap<&str, Vec<&Alert>> = HashMap::new();
+    for alert in alerts {
+        if let Some(alert_key) = &alert.alert_key {
+            by_key.entry(alert_key.as_str()).or_default().push(alert);
+        }
+    }
+
+    let mut flapping: Vec<FlappingAlertKey> = by_key.into_iter()
+        .filter_map(|(alert_key, mut key_alerts)| {
+            key_alerts.sort_by_key(|alert| alert.report_time);
+
+            let reopened: Vec<DateTime<Utc>> = key_alerts.windows(2)
+                .filter(|pair| pair[0].status == AlertState::RESOLVED
+                    && pair[1].report_time >= pair[0].resolved_on.unwrap_or(pair[0].report_time))
+                .map(|pair| pair[1].report_time)
+                .collect();
+
+            let mut start = 0;
+            let mut transitions = 0;
+            for end in 0..reopened.len() {
+                while reopened[end] - reopened[start] > window {
+                    start += 1;
+                }
+                transitions = transitions.max(end - start + 1);
+            }
+
+            if transitions >= min_transitions.max(1) {
+                Some(FlappingAlertKey {
+                    alert_key: alert_key.to_string(),
+                    transitions
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    flapping.sort_by(|a, b| b.transitions.cmp(&a.transitions).then_with(|| a.alert_key.cmp(&b.alert_key)));
+    flapping
+}
+
+/// The `limit` alert sources with the most alerts, highest first.
+pub fn noisy_alert_sources(alerts: &[Alert], limit: usize) -> Vec<NoisyAlertSource> {
+
+    let mut counts: BTreeMap<Option<i64>, NoisyAlertSource> = BTreeMap::new();
+    for alert in alerts {
+        let alert_source_id = alert.alert_source.as_ref().map(|source| source.id);
+        let entry = counts.entry(alert_source_id).or_insert_with(|| NoisyAlertSource {
+            alert_source_id,
+            name: alert.alert_source.as_ref().and_then(|source| source.name.clone()),
+            count: 0
+        });
+        entry.count += 1;
+    }
+
+    let mut noisy: Vec<NoisyAlertSource> = counts.into_values().collect();
+    noisy.sort_by_key(|source| std::cmp::Reverse(source.count));
+    noisy.truncate(limit);
+    noisy
+}
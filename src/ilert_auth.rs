@@ -0,0 +1,176 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use base64::engine::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use crate::ilert_error::{ILertResult, ILertError};
+use crate::ilert_redact::REDACTED;
+
+/// Source of the `Authorization` header, asked for every request so rotated credentials
+/// take effect without rebuilding the client. Implementations must not reveal secrets via `Debug`.
+pub trait AuthProvider: Send + Sync + fmt::Debug {
+    fn authorization(&self) -> ILertResult<String>;
+}
+
+/* ### STATIC TOKEN ### */
+
+#[derive(Clone)]
+pub struct TokenAuth {
+    token: String,
+}
+
+impl TokenAuth {
+    pub fn new(token: &str) -> TokenAuth {
+        TokenAuth {
+            token: token.to_string()
+        }
+    }
+}
+
+impl AuthProvider for TokenAuth {
+    fn authorization(&self) -> ILertResult<String> {
+        Ok(format!("Bearer {}", self.token))
+    }
+}
+
+impl fmt::Debug for TokenAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenAuth").field("token", &REDACTED).finish()
+    }
+}
+
+/* ### BASIC AUTH ### */
+
+#[derive(Clone)]
+pub struct BasicAuth {
+    user: String,
+    psw: String,
+}
+
+impl BasicAuth {
+    pub fn new(user: &str, psw: &str) -> BasicAuth {
+        BasicAuth {
+            user: user.to_string(),
+            psw: psw.to_string()
+        }
+    }
+}
+
+impl AuthProvider for BasicAuth {
+    fn authorization(&self) -> ILertResult<String> {
+        let basic_string = format!("{}:{}", self.user.as_str(), self.psw.as_str());
+        Ok(format!("Basic {}", BASE64.encode(basic_string.as_str())))
+    }
+}
+
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("user", &self.user)
+            .field("psw", &REDACTED)
+            .finish()
+    }
+}
+
+/* ### TOKEN FILE ### */
+
+// modification time and size of the token file when it was last read
+type FileVersion = Option<(SystemTime, u64)>;
+
+/// Token read from a file (e.g. a mounted kubernetes secret), re-read whenever the file's modification time or size changes
+/// and at least every `reload_interval` (default 60s), as a rotation may keep both.
+pub struct FileTokenAuth {
+    path: PathBuf,
+    reload_interval: Duration,
+    cached: Mutex<Option<(FileVersion, Instant, String)>>,
+}
+
+impl FileTokenAuth {
+
+    pub fn new<P: AsRef<Path>>(path: P) -> FileTokenAuth {
+        FileTokenAuth {
+            path: path.as_ref().to_path_buf(),
+            reload_interval: Duration::from_secs(60),
+            cached: Mutex::new(None)
+        }
+    }
+
+    pub fn reload_interval(mut self, reload_interval: Duration) -> Self {
+        self.reload_interval = reload_interval;
+        self
+    }
+
+    fn read_token(&self) -> ILertResult<String> {
+
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|metadata| Ok((metadata.modified()?, metadata.len())))
+            .ok();
+
+        let mut cached = self.cached.lock()
+            .map_err(|_| ILertError::new("Token file cache is poisoned."))?;
+
+        if let Some((cached_modified, read_at, token)) = cached.as_ref() {
+            if modified.is_some() && *cached_modified == modified && read_at.elapsed() < self.reload_interval {
+                return Ok(token.clone());
+            }
+        }
+
+        let token = std::fs::read_to_string(&self.path)
+            .map_err(|err| ILertError::new(format!("Failed to read token file '{}': {}.", self.path.display(), err).as_str()))?
+            .trim()
+            .to_string();
+
+        if token.is_empty() {
+            return Err(ILertError::new(format!("Token file '{}' is empty.", self.path.display()).as_str()));
+        }
+
+        *cached = Some((modified, Instant::now(), token.clone()));
+        Ok(token)
+    }
+}
+
+impl AuthProvider for FileTokenAuth {
+    fn authorization(&self) -> ILertResult<String> {
+        Ok(format!("Bearer {}", self.read_token()?))
+    }
+}
+
+impl fmt::Debug for FileTokenAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileTokenAuth")
+            .field("path", &self.path)
+            .field("reload_interval", &self.reload_interval)
+            .finish()
+    }
+}
+
+/* ### CALLBACK ### */
+
+/// Token returned by a callback, e.g. fetched from a secret manager; caching is up to the callback.
+#[derive(Clone)]
+pub struct CallbackTokenAuth {
+    callback: Arc<dyn Fn() -> ILertResult<String> + Send + Sync>,
+}
+
+impl CallbackTokenAuth {
+    pub fn new<F>(callback: F) -> CallbackTokenAuth where F: Fn() -> ILertResult<String> + Send + Sync + 'static {
+        CallbackTokenAuth {
+            callback: Arc::new(callback)
+        }
+    }
+}
+
+impl AuthProvider for CallbackTokenAuth {
+    fn authorization(&self) -> ILertResult<String> {
+        Ok(format!("Bearer {}", (self.callback)()?))
+    }
+}
+
+impl fmt::Debug for CallbackTokenAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackTokenAuth").finish_non_exhaustive()
+    }
+}
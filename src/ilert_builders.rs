@@ -1,13 +1,24 @@
-use reqwest::header::{HeaderMap, HeaderValue, HeaderName};
+use http::header::{HeaderMap, HeaderValue, HeaderName};
 use base64;
-use reqwest::StatusCode;
+use http::StatusCode;
 use serde_json::{Result, Value};
 use serde_json::json;
 use serde_derive::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
 
 use crate::ilert::ILert;
 use crate::ilert_error::{ILertResult, ILertError};
+use crate::ilert_filters::ILertFilter;
+use crate::ilert_redact::{redact_body, redact_json, redact_url, RedactedBody, RedactedHeaders};
+use log::debug;
+use http::Method;
+use std::time::{Duration, Instant};
+use crate::ilert_metrics::{RequestMetrics, StatusClass};
+use crate::ilert_transport::TransportRequest;
+use crate::ilert_rate_limit::{EndpointClass, RateLimitConfig, RateLimitMode};
+use crate::ilert_models::{MaintenanceWindow, StatusPage, StatusPageGroup, Subscriber, Connector, AlertAction, SupportHours, Metric, MetricDataSource, MetricDataPoint, DeploymentPipeline, DeploymentEvent, CallFlow};
 use std::error::Error;
+use std::fmt;
 
 use base64::engine::Engine as _;
 use base64::engine::general_purpose::STANDARD as BASE64;
@@ -19,11 +30,46 @@ pub enum ILertEventType {
     COMMENT
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
 pub enum ILertPriority {
     HIGH,
     LOW,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum AlertState {
+    PENDING,
+    ACCEPTED,
+    RESOLVED,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IncidentState {
+    INVESTIGATING,
+    IDENTIFIED,
+    MONITORING,
+    RESOLVED,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ServiceStatus {
+    OPERATIONAL,
+    UNDER_MAINTENANCE,
+    DEGRADED,
+    PARTIAL_OUTAGE,
+    MAJOR_OUTAGE,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UserRole {
+    ADMIN,
+    USER,
+    RESPONDER,
+    STAKEHOLDER,
+    GUEST,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct EventImage {
     pub src: String,
@@ -75,13 +121,14 @@ impl ILertEventType {
 
     pub fn as_str(&self) -> &str {
         match self {
-            &ILertEventType::ALERT => "ALERT",
-            &ILertEventType::ACCEPT => "ACCEPT",
-            &ILertEventType::RESOLVE => "RESOLVE",
-            &ILertEventType::COMMENT => "COMMENT",
+            ILertEventType::ALERT => "ALERT",
+            ILertEventType::ACCEPT => "ACCEPT",
+            ILertEventType::RESOLVE => "RESOLVE",
+            ILertEventType::COMMENT => "COMMENT",
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(val: &str) -> ILertResult<ILertEventType> {
         match val {
             "ALERT" => Ok(ILertEventType::ALERT),
@@ -97,11 +144,12 @@ impl ILertPriority {
 
     pub fn as_str(&self) -> &str {
         match self {
-            &ILertPriority::HIGH => "HIGH",
-            &ILertPriority::LOW => "LOW",
+            ILertPriority::HIGH => "HIGH",
+            ILertPriority::LOW => "LOW",
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(val: &str) -> ILertResult<ILertPriority> {
         match val {
             "HIGH" => Ok(ILertPriority::HIGH),
@@ -111,18 +159,126 @@ impl ILertPriority {
     }
 }
 
-#[derive(Debug, Clone)]
+impl AlertState {
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            AlertState::PENDING => "PENDING",
+            AlertState::ACCEPTED => "ACCEPTED",
+            AlertState::RESOLVED => "RESOLVED",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(val: &str) -> ILertResult<AlertState> {
+        match val {
+            "PENDING" => Ok(AlertState::PENDING),
+            "ACCEPTED" => Ok(AlertState::ACCEPTED),
+            "RESOLVED" => Ok(AlertState::RESOLVED),
+            _ => Err(ILertError::new("Unsupported type value.")),
+        }
+    }
+}
+
+impl IncidentState {
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            IncidentState::INVESTIGATING => "INVESTIGATING",
+            IncidentState::IDENTIFIED => "IDENTIFIED",
+            IncidentState::MONITORING => "MONITORING",
+            IncidentState::RESOLVED => "RESOLVED",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(val: &str) -> ILertResult<IncidentState> {
+        match val {
+            "INVESTIGATING" => Ok(IncidentState::INVESTIGATING),
+            "IDENTIFIED" => Ok(IncidentState::IDENTIFIED),
+            "MONITORING" => Ok(IncidentState::MONITORING),
+            "RESOLVED" => Ok(IncidentState::RESOLVED),
+            _ => Err(ILertError::new("Unsupported type value.")),
+        }
+    }
+}
+
+impl ServiceStatus {
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            ServiceStatus::OPERATIONAL => "OPERATIONAL",
+            ServiceStatus::UNDER_MAINTENANCE => "UNDER_MAINTENANCE",
+            ServiceStatus::DEGRADED => "DEGRADED",
+            ServiceStatus::PARTIAL_OUTAGE => "PARTIAL_OUTAGE",
+            ServiceStatus::MAJOR_OUTAGE => "MAJOR_OUTAGE",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(val: &str) -> ILertResult<ServiceStatus> {
+        match val {
+            "OPERATIONAL" => Ok(ServiceStatus::OPERATIONAL),
+            "UNDER_MAINTENANCE" => Ok(ServiceStatus::UNDER_MAINTENANCE),
+            "DEGRADED" => Ok(ServiceStatus::DEGRADED),
+            "PARTIAL_OUTAGE" => Ok(ServiceStatus::PARTIAL_OUTAGE),
+            "MAJOR_OUTAGE" => Ok(ServiceStatus::MAJOR_OUTAGE),
+            _ => Err(ILertError::new("Unsupported type value.")),
+        }
+    }
+}
+
+impl UserRole {
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            UserRole::ADMIN => "ADMIN",
+            UserRole::USER => "USER",
+            UserRole::RESPONDER => "RESPONDER",
+            UserRole::STAKEHOLDER => "STAKEHOLDER",
+            UserRole::GUEST => "GUEST",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(val: &str) -> ILertResult<UserRole> {
+        match val {
+            "ADMIN" => Ok(UserRole::ADMIN),
+            "USER" => Ok(UserRole::USER),
+            "RESPONDER" => Ok(UserRole::RESPONDER),
+            "STAKEHOLDER" => Ok(UserRole::STAKEHOLDER),
+            "GUEST" => Ok(UserRole::GUEST),
+            _ => Err(ILertError::new("Unsupported type value.")),
+        }
+    }
+}
+
+#[derive(Clone)]
 struct BaseRequestOptions {
     path: Option<String>,
+    path_template: Option<String>,
     url: Option<String>,
     headers: HeaderMap,
     body: Option<String>,
 }
 
+impl fmt::Debug for BaseRequestOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BaseRequestOptions")
+            .field("path", &self.path.as_deref().map(redact_url))
+            .field("path_template", &self.path_template)
+            .field("url", &self.url.as_deref().map(redact_url))
+            .field("headers", &RedactedHeaders(&self.headers))
+            .field("body", &RedactedBody(&self.body))
+            .finish()
+    }
+}
+
 impl BaseRequestOptions {
     pub fn new() -> BaseRequestOptions {
         BaseRequestOptions {
             path: None,
+            path_template: None,
             url: None,
             headers: HeaderMap::new(),
             body: None
@@ -136,7 +292,9 @@ struct BaseRequestBuilder<'a> {
     options: BaseRequestOptions,
     start_index: Option<i64>,
     max_results: Option<i32>,
-    filters: Option<Vec<(String, String)>>
+    filters: Option<Vec<(String, String)>>,
+    force_json: bool,
+    error: Option<String>
 }
 
 impl<'a> BaseRequestBuilder<'a> {
@@ -147,19 +305,51 @@ impl<'a> BaseRequestBuilder<'a> {
             options: BaseRequestOptions::new(),
             start_index: None,
             max_results: None,
-            filters: None
+            filters: None,
+            force_json: false,
+            error: None
         }
     }
 
-    fn set_path(&mut self, path: &str) -> () {
+    fn set_path(&mut self, path: &str) {
         self.options.path = Some(path.to_string());
+        self.options.path_template = Some(path.to_string());
+    }
+
+    // fills the {..} placeholders of the template in order, the template itself is kept for logs and metrics
+    fn set_path_template(&mut self, template: &str, params: &[&dyn fmt::Display]) {
+        let mut path = String::with_capacity(template.len());
+        let mut rest = template;
+        let mut params = params.iter();
+        while let Some(start) = rest.find('{') {
+            let end = match rest[start..].find('}') {
+                Some(end) => start + end,
+                None => break,
+            };
+            path.push_str(&rest[..start]);
+            if let Some(param) = params.next() {
+                path.push_str(param.to_string().as_str());
+            }
+            rest = &rest[end + 1..];
+        }
+        path.push_str(rest);
+
+        self.options.path = Some(path);
+        self.options.path_template = Some(template.to_string());
     }
 
-    fn set_body(&mut self, body: &str) -> () {
+    fn set_body(&mut self, body: &str) {
         self.options.body = Some(body.to_string());
     }
 
-    fn add_filter(&mut self, key: &str, val: &str) -> () {
+    fn set_entity_body<T: serde::Serialize>(&mut self, entity: &T) {
+        match serde_json::to_string(entity) {
+            Ok(body) => self.options.body = Some(body),
+            Err(err) => self.error = Some(err.to_string()),
+        }
+    }
+
+    fn add_filter(&mut self, key: &str, val: &str) {
 
         if self.filters.is_none() {
             self.filters = Some(Vec::new());
@@ -169,26 +359,159 @@ impl<'a> BaseRequestBuilder<'a> {
     }
 }
 
-#[derive(Debug)]
 pub struct BaseRequestResult {
     pub url: String,
     pub status: StatusCode,
     pub headers: HeaderMap,
     pub body_raw: Option<String>,
     pub body_json: Option<Value>,
+    /// `skip()` and `limit()` values of the request, used by `page()`.
+    pub start_index: Option<i64>,
+    pub max_results: Option<i32>,
+}
+
+impl fmt::Debug for BaseRequestResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BaseRequestResult")
+            .field("url", &redact_url(self.url.as_str()))
+            .field("status", &self.status)
+            .field("headers", &RedactedHeaders(&self.headers))
+            .field("body_raw", &RedactedBody(&self.body_raw))
+            .field("body_json", &self.body_json.as_ref().map(redact_json))
+            .field("start_index", &self.start_index)
+            .field("max_results", &self.max_results)
+            .finish()
+    }
 }
 
 impl BaseRequestResult {
 
-     fn new(url: String, status: StatusCode, headers: HeaderMap, body_raw: Option<String>, body_json: Option<Value>) -> BaseRequestResult {
+     fn new(url: String, status: StatusCode, headers: HeaderMap, body_raw: Option<String>, body_json: Option<Value>,
+            start_index: Option<i64>, max_results: Option<i32>) -> BaseRequestResult {
         BaseRequestResult {
             url,
             status,
             headers,
             body_raw,
             body_json,
+            start_index,
+            max_results,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+
+    /// Error details of a non 2xx response, falls back to the raw body as message if it is not an ilert error object
+    /// (i.e. has neither `message` nor `code`), `status` defaults to the response status.
+    pub fn error_body(&self) -> Option<ErrorBody> {
+
+        if self.is_success() {
+            return None;
+        }
+
+        let decoded = self.body_json.as_ref()
+            .and_then(|body_json| serde_json::from_value::<ErrorBody>(body_json.clone()).ok())
+            .filter(|error_body| error_body.message.is_some() || error_body.code.is_some());
+
+        let mut error_body = decoded.unwrap_or_else(|| ErrorBody {
+            status: None,
+            code: None,
+            message: self.body_raw.clone().filter(|body_raw| !body_raw.trim().is_empty()),
+        });
+        error_body.status = error_body.status.or(Some(self.status.as_u16()));
+        Some(error_body)
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    fn header_number(&self, name: &str) -> Option<u64> {
+        self.header(name).and_then(|value| value.trim().parse::<u64>().ok())
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.header(REQUEST_ID_HEADER)
+    }
+
+    pub fn rate_limit_limit(&self) -> Option<u64> {
+        self.header_number(RATE_LIMIT_LIMIT_HEADER)
+    }
+
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        self.header_number(RATE_LIMIT_REMAINING_HEADER)
+    }
+
+    /// Value of `x-ratelimit-reset`, as sent by the server.
+    pub fn rate_limit_reset(&self) -> Option<u64> {
+        self.header_number(RATE_LIMIT_RESET_HEADER)
+    }
+
+    /// `Retry-After` given in seconds or as HTTP-date.
+    pub fn retry_after(&self) -> Option<Duration> {
+        let value = self.header(http::header::RETRY_AFTER.as_str())?.trim();
+        match value.parse::<u64>() {
+            Ok(seconds) => Some(Duration::from_secs(seconds)),
+            // HTTP-date, dates in the past mean right away
+            Err(_) => chrono::DateTime::parse_from_rfc2822(value).ok()
+                .map(|at| (at.with_timezone(&chrono::Utc) - chrono::Utc::now()).to_std().unwrap_or_default()),
         }
     }
+
+    /// Decodes a list response together with the `skip()` and `limit()` values of the request to page further.
+    pub fn page<T: DeserializeOwned>(&self) -> ILertResult<Page<T>> {
+        Ok(Page {
+            items: self.json()?,
+            start_index: self.start_index.unwrap_or(0),
+            max_results: self.max_results,
+        })
+    }
+
+    /// Decodes the body into `T`, also if the response was not declared as json.
+    pub fn json<T: DeserializeOwned>(&self) -> ILertResult<T> {
+
+        let decoded = match (&self.body_json, &self.body_raw) {
+            (Some(body_json), _) => serde_json::from_value(body_json.clone()),
+            (None, Some(body_raw)) => serde_json::from_str(body_raw.as_str()),
+            (None, None) => return Err(ILertError::new(format!("Response {} has no body.", self.status.as_u16()).as_str())),
+        };
+
+        decoded.map_err(|err| ILertError::new(format!("Failed to decode response {}: {}.", self.status.as_u16(), err).as_str()))
+    }
+}
+
+/// Error object returned by the ilert API for non 2xx responses.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all="camelCase")]
+pub struct ErrorBody {
+    pub status: Option<u16>,
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+/// One page of a list response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub start_index: i64,
+    pub max_results: Option<i32>,
+}
+
+impl<T> Page<T> {
+
+    /// True if the page is full, ilert does not return totals so one more (possibly empty) page may follow.
+    pub fn has_more(&self) -> bool {
+        self.max_results
+            .map(|max_results| max_results > 0 && self.items.len() >= max_results as usize)
+            .unwrap_or(false)
+    }
+
+    /// Value for `skip()` to fetch the next page.
+    pub fn next_start_index(&self) -> i64 {
+        self.start_index + self.items.len() as i64
+    }
 }
 
 pub trait BaseRequestExecutor {
@@ -200,6 +523,10 @@ fn prepare_generic_request_builder (builder: &BaseRequestBuilder) -> ILertResult
     let ilertref = builder._ilert;
     let mut options = builder.options.clone();
 
+    if let Some(err) = &builder.error {
+        return Err(ILertError::new(err.as_str()));
+    }
+
     if builder.options.path.is_none() {
         return Err(ILertError::new("Failed to build url, path missing."));
     }
@@ -207,26 +534,217 @@ fn prepare_generic_request_builder (builder: &BaseRequestBuilder) -> ILertResult
     let url = ilertref.build_url(builder.options.path.as_ref().unwrap().as_str());
     options.url = Some(url);
 
-    match ilertref.api_token.clone() {
-        Some(token) => options.headers
-            .append("Authorization", HeaderValue::from_str(format!("Bearer {}", token).as_str()).unwrap()),
-        None => false,
+    // repeated per request as injected http clients do not carry our default headers
+    options.headers.insert("Accept", HeaderValue::from_static("application/json"));
+    options.headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+
+    if let Some(provider) = ilertref.auth_provider() {
+        let mut auth_value = HeaderValue::from_str(provider.authorization()?.as_str())
+            .map_err(|_| ILertError::new("Invalid characters in authorization credentials."))?;
+        auth_value.set_sensitive(true);
+        options.headers.insert("Authorization", auth_value);
+    }
+
+    Ok(options)
+}
+
+// ilert request id echoed in responses, used to correlate client logs with ilert support
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// True for `application/json` and `+json` media types (e.g. `application/problem+json`), parameters like `charset` are ignored.
+pub fn is_json_content_type(content_type: &str) -> bool {
+
+    let media_type = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    let (main_type, sub_type) = match media_type.split_once('/') {
+        Some(value) => value,
+        None => return false,
     };
 
-    match ilertref.auth_user.clone() {
-        Some(user) => match ilertref.auth_psw.clone() {
-            Some(psw) => {
-                let basic_string = format!("{}:{}", user.as_str(), psw.as_str());
-                let basic_auth_string = format!("Basic {}", BASE64.encode(basic_string.as_str()));
-                options.headers
-                    .append("Authorization", HeaderValue::from_str(basic_auth_string.as_str()).unwrap());
+    main_type == "application" && (sub_type == "json" || sub_type.ends_with("+json"))
+}
+
+fn parse_response(builder: &BaseRequestBuilder, url: String, response_status: StatusCode, response_headers: HeaderMap, body_raw: Option<String>) -> ILertResult<BaseRequestResult> {
+
+    let is_json = builder.force_json || response_headers.get("content-type")
+        .and_then(|ct_value| ct_value.to_str().ok())
+        .map(is_json_content_type)
+        .unwrap_or(false);
+
+    let body_json = match &body_raw {
+        Some(raw_value) if is_json && !raw_value.trim().is_empty() =>
+            match serde_json::from_str(raw_value.as_str()) {
+                Ok(parsed_json) => Some(parsed_json),
+                Err(err) => {
+                    // e.g. an html error page of a proxy, the status and raw body are still returned
+                    debug!("Response of {} is not valid json: {}", redact_url(url.as_str()), err);
+                    None
+                },
             },
-            None => (),
+        _ => None,
+    };
+
+    Ok(BaseRequestResult::new(
+        url,
+        response_status,
+        response_headers,
+        body_raw,
+        body_json,
+        builder.start_index,
+        builder.max_results,
+    ))
+}
+
+fn record_metrics(ilert: &ILert, method: &Method, path_template: &str, status: Option<u16>, latency: Duration, attempt: u32) {
+    if let Some(hook) = ilert.metrics_hook() {
+        hook.on_request(&RequestMetrics {
+            method: method.as_str(),
+            path_template,
+            status,
+            status_class: StatusClass::from_status(status),
+            latency,
+            attempt,
+        });
+    }
+}
+
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// Shared pipeline every `BaseRequestExecutor::execute()` runs through.
+fn execute_request(builder: &BaseRequestBuilder, method: Method) -> ILertResult<BaseRequestResult> {
+
+    let options = prepare_generic_request_builder(builder)?;
+
+    if options.url.is_none() {
+        return Err(ILertError::new("Failed to build url."));
+    }
+    let url = options.url.unwrap();
+    let path_template = options.path_template.unwrap_or_default();
+
+    let rate_limiter = builder._ilert.rate_limiter();
+    let endpoint_class = EndpointClass::from_path(path_template.as_str());
+    let max_attempts = match rate_limiter.map(|limiter| limiter.config()) {
+        Some(RateLimitConfig { mode: RateLimitMode::WAIT(_), max_retries_on_429, .. }) => max_retries_on_429 + 1,
+        _ => 1,
+    };
+
+    let mut attempt = 1;
+    let mut rate_limited: Option<BaseRequestResult> = None;
+    loop {
+        if let Some(limiter) = rate_limiter {
+            if let Err(err) = limiter.acquire(endpoint_class) {
+                // a retry that can not be sent within the max wait (e.g. a long Retry-After) returns the last 429
+                return match rate_limited {
+                    Some(result) => Ok(result),
+                    None => Err(err),
+                };
+            }
+        }
+
+        let result = send_request(builder, &method, &url, &path_template, &options.headers, options.body.as_ref(), attempt)?;
+
+        if let Some(limiter) = rate_limiter {
+            if result.status == StatusCode::TOO_MANY_REQUESTS {
+                limiter.on_rate_limited(endpoint_class, result.retry_after());
+                if attempt < max_attempts {
+                    debug!("{} {} was rate limited, retrying (attempt {})", method, path_template, attempt + 1);
+                    attempt += 1;
+                    rate_limited = Some(result);
+                    continue;
+                }
+            } else {
+                if result.rate_limit_remaining() == Some(0) {
+                    limiter.on_quota_exhausted(endpoint_class);
+                }
+                limiter.on_success(endpoint_class);
+            }
+        }
+
+        return Ok(result);
+    }
+}
+
+fn send_request(builder: &BaseRequestBuilder, method: &Method, url: &str, path_template: &str,
+                headers: &HeaderMap, body: Option<&String>, attempt: u32) -> ILertResult<BaseRequestResult> {
+
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!("ilert.request",
+        http.method = %method,
+        ilert.path = %path_template,
+        ilert.attempt = attempt,
+        http.status_code = tracing::field::Empty,
+        ilert.latency_ms = tracing::field::Empty,
+        ilert.request_id = tracing::field::Empty);
+    #[cfg(feature = "tracing")]
+    let _entered = span.enter();
+
+    let mut query: Vec<(String, String)> = Vec::new();
+
+    if let Some(start_index) = builder.start_index {
+        query.push(("start-index".to_string(), start_index.to_string()));
+    }
+
+    if let Some(max_results) = builder.max_results {
+        query.push(("max-results".to_string(), max_results.to_string()));
+    }
+
+    if let Some(filters) = &builder.filters {
+        query.extend(filters.iter().cloned());
+    }
+
+    let mut request_url = url::Url::parse(url)
+        .map_err(|err| ILertError::new(format!("Failed to build url: {}.", err).as_str()))?;
+    if !query.is_empty() {
+        request_url.query_pairs_mut().extend_pairs(query.iter());
+    }
+
+    #[cfg(feature = "tracing")]
+    if let Some(body) = body {
+        tracing::trace!(body = %redact_body(body.as_str()), "ilert request body");
+    }
+
+    let request = TransportRequest {
+        method: method.clone(),
+        url: request_url.to_string(),
+        headers: headers.clone(),
+        body: body.cloned(),
+    };
+
+    let started = Instant::now();
+    let response = match builder._ilert.transport().execute(request) {
+        Ok(value) => value,
+        Err(err) => {
+            record_metrics(builder._ilert, method, path_template, None, started.elapsed(), attempt);
+            debug!("{} {} failed after {}ms: {}", method, path_template, started.elapsed().as_millis(), err);
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %err, "ilert request failed");
+            return Err(err);
         },
-        None => (),
     };
 
-    Ok(options)
+    let status = response.status;
+    let result = parse_response(builder, url.to_string(), response.status, response.headers, response.body);
+    let latency = started.elapsed();
+    let latency_ms = latency.as_millis() as u64;
+    record_metrics(builder._ilert, method, path_template, Some(status.as_u16()), latency, attempt);
+
+    if let Ok(result) = &result {
+        let request_id = result.request_id().unwrap_or("-");
+        debug!("{} {} -> {} in {}ms (request id {})", method, path_template, result.status.as_u16(), latency_ms, request_id);
+
+        #[cfg(feature = "tracing")]
+        {
+            span.record("http.status_code", result.status.as_u16());
+            span.record("ilert.latency_ms", latency_ms);
+            span.record("ilert.request_id", request_id);
+            if let Some(body_raw) = &result.body_raw {
+                tracing::trace!(body = %redact_body(body_raw.as_str()), "ilert response body");
+            }
+        }
+    }
+
+    result
 }
 
 /* ### API Implementations ### */
@@ -239,6 +757,7 @@ pub trait EventApiResource {
 
     fn event(&mut self, api_key: &str, event_type: ILertEventType, summary: Option<String>, alert_key: Option<String>) -> Box<&dyn BaseRequestExecutor>;
 
+    #[allow(clippy::too_many_arguments)]
     fn event_with_details(&mut self, api_key: &str, event_type: ILertEventType, summary: Option<String>,
             alert_key: Option<String>, details: Option<String>, priority: Option<ILertPriority>, images: Option<Vec<EventImage>>,
             links: Option<Vec<EventLink>>, custom_details: Option<serde_json::Value>, routing_key: Option<String>) -> Box<&dyn BaseRequestExecutor>;
@@ -246,6 +765,10 @@ pub trait EventApiResource {
     fn event_with_comment(&mut self, api_key: &str, alert_key: Option<String>, comments: Option<Vec<EventComment>>) -> Box<&dyn BaseRequestExecutor>;
 }
 
+pub trait DeploymentEventApiResource {
+    fn deployment_event(&mut self, integration_key: &str, event: &DeploymentEvent) -> Box<&dyn BaseRequestExecutor>;
+}
+
 /* ### USERS ### */
 
 pub trait UserGetApiResource {
@@ -266,6 +789,7 @@ pub trait ScheduleGetApiResource {
 pub trait AlertGetApiResource {
     fn alerts(&mut self) -> Box<&dyn BaseRequestExecutor>;
     fn alert(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn alert_log_entries(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
 pub trait AlertPutApiResource {
@@ -280,11 +804,13 @@ pub trait AlertPutApiResource {
 pub trait IncidentGetApiResource {
     fn incidents(&mut self) -> Box<&dyn BaseRequestExecutor>;
     fn incident(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn incident_subscribers(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
 pub trait IncidentPostApiResource {
     // incident_raw() leaving incident() open for a typed implementation
     fn incident_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+    fn incident_subscribers(&mut self, id: i64, subscribers: &[Subscriber]) -> Box<&dyn BaseRequestExecutor>;
 }
 
 pub trait IncidentPutApiResource {
@@ -294,6 +820,7 @@ pub trait IncidentPutApiResource {
 
 pub trait IncidentDeleteApiResource {
     fn incident(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn incident_subscriber(&mut self, id: i64, subscriber_id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
 /* ### SERVICES ### */
@@ -301,11 +828,13 @@ pub trait IncidentDeleteApiResource {
 pub trait ServiceGetApiResource {
     fn services(&mut self) -> Box<&dyn BaseRequestExecutor>;
     fn service(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn service_subscribers(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
 pub trait ServicePostApiResource {
     // service_raw() leaving service() open for a typed implementation
     fn service_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+    fn service_subscribers(&mut self, id: i64, subscribers: &[Subscriber]) -> Box<&dyn BaseRequestExecutor>;
 }
 
 pub trait ServicePutApiResource {
@@ -315,473 +844,1020 @@ pub trait ServicePutApiResource {
 
 pub trait ServiceDeleteApiResource {
     fn service(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn service_subscriber(&mut self, id: i64, subscriber_id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
-/* ### GET ### */
+/* ### MAINTENANCE WINDOWS ### */
 
-#[derive(Debug, Clone)]
-pub struct GetRequestBuilder<'a> {
-    builder: BaseRequestBuilder<'a>,
+pub trait MaintenanceWindowGetApiResource {
+    fn maintenance_windows(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn maintenance_window(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
-impl<'a> GetRequestBuilder<'a> {
+pub trait MaintenanceWindowPostApiResource {
+    fn maintenance_window(&mut self, entity: &MaintenanceWindow) -> Box<&dyn BaseRequestExecutor>;
+    fn maintenance_window_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
 
-    pub fn new(_ilert: &'a ILert) -> GetRequestBuilder<'a> {
-        GetRequestBuilder {
-            builder: BaseRequestBuilder::new(_ilert),
-        }
-    }
+pub trait MaintenanceWindowPutApiResource {
+    fn maintenance_window(&mut self, id: i64, entity: &MaintenanceWindow) -> Box<&dyn BaseRequestExecutor>;
+    fn maintenance_window_raw(&mut self, id: i64, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
 
-    pub fn skip(mut self, start_index: i64) -> Self {
-        self.builder.start_index = Some(start_index);
-        self
-    }
+pub trait MaintenanceWindowDeleteApiResource {
+    fn maintenance_window(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
 
-    pub fn limit(mut self, max_results: i32) -> Self {
-        self.builder.max_results = Some(max_results);
-        self
-    }
+/* ### STATUS PAGES ### */
 
-    pub fn filter(mut self, key: &str, val: &str) -> Self {
-        self.builder.add_filter(key, val);
-        self
-    }
+pub trait StatusPageGetApiResource {
+    fn status_pages(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_groups(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_group(&mut self, id: i64, group_id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_subscribers(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
-impl BaseRequestExecutor for GetRequestBuilder<'_> {
-
-    fn execute(&self) -> ILertResult<BaseRequestResult> {
+pub trait StatusPagePostApiResource {
+    fn status_page(&mut self, entity: &StatusPage) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_group(&mut self, id: i64, entity: &StatusPageGroup) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_subscribers(&mut self, id: i64, subscribers: &[Subscriber]) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        let options_result = prepare_generic_request_builder(&self.builder);
-        if options_result.is_err() {
-            return Err(options_result.unwrap_err());
-        }
-        let options = options_result.unwrap();
+pub trait StatusPagePutApiResource {
+    fn status_page(&mut self, id: i64, entity: &StatusPage) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_raw(&mut self, id: i64, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_group(&mut self, id: i64, group_id: i64, entity: &StatusPageGroup) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        if options.url.is_none() {
-            return Err(ILertError::new("Failed to build url."));
-        }
-        let url = options.url.unwrap();
+pub trait StatusPageDeleteApiResource {
+    fn status_page(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_group(&mut self, id: i64, group_id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn status_page_subscriber(&mut self, id: i64, subscriber_id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        let mut request_builder = self.builder._ilert.http_client
-            .get(url.as_str())
-            .headers(options.headers);
+/* ### CONNECTORS ### */
 
-        if let Some(start_index) = self.builder.start_index {
-            request_builder = request_builder.query(&[("start-index", start_index)]);
-        }
+pub trait ConnectorGetApiResource {
+    fn connectors(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn connector(&mut self, id: &str) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        if let Some(max_results) = self.builder.max_results {
-            request_builder = request_builder.query(&[("max-results", max_results)]);
-        }
+pub trait ConnectorPostApiResource {
+    fn connector(&mut self, entity: &Connector) -> Box<&dyn BaseRequestExecutor>;
+    fn connector_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        if let Some(filters) = &self.builder.filters {
-            request_builder = request_builder.query(filters);
-        }
+pub trait ConnectorPutApiResource {
+    fn connector(&mut self, id: &str, entity: &Connector) -> Box<&dyn BaseRequestExecutor>;
+    fn connector_raw(&mut self, id: &str, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        let response_result = request_builder.send();
+pub trait ConnectorDeleteApiResource {
+    fn connector(&mut self, id: &str) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        let mut response = match response_result {
-            Ok(value) => value,
-            Err(err) => {
-                return Err(ILertError::new(err.to_string().as_str()));
-            },
-        };
+/* ### ALERT ACTIONS ### */
 
-        let response_status = response.status().clone();
-        let response_headers = response.headers().clone();
+pub trait AlertActionGetApiResource {
+    fn alert_actions(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn alert_action(&mut self, id: &str) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        let body_raw = match response.text() {
-            Ok(value) => Some(value.clone()),
-            Err(_) => None,
-        };
+pub trait AlertActionPostApiResource {
+    fn alert_action(&mut self, entity: &AlertAction) -> Box<&dyn BaseRequestExecutor>;
+    fn alert_action_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        let body_json = match body_raw.clone() {
-            Some(raw_value) =>
-                match response_headers.get("content-type") {
-                    Some(ct_value) =>
-                        if ct_value.eq(&"application/json") {
-                            let parsed_json_result = serde_json::from_str(raw_value.as_str());
-                            match parsed_json_result {
-                                Ok(parsed_json) => Some(parsed_json),
-                                Err(err) => {
-                                    return Err(ILertError::new(err.to_string().as_str()));
-                                },
-                            }
-                        } else {
-                            None
-                        },
-                    None => None,
-                },
-            None => None,
-        };
+pub trait AlertActionPutApiResource {
+    fn alert_action(&mut self, id: &str, entity: &AlertAction) -> Box<&dyn BaseRequestExecutor>;
+    fn alert_action_raw(&mut self, id: &str, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
 
-        Ok(BaseRequestResult::new(
-            url,
-            response_status,
-            response_headers,
-            body_raw,
-            body_json,
-        ))
-    }
+pub trait AlertActionDeleteApiResource {
+    fn alert_action(&mut self, id: &str) -> Box<&dyn BaseRequestExecutor>;
 }
 
-impl HeartbeatApiResource for GetRequestBuilder<'_> {
+/* ### SUPPORT HOURS ### */
 
-    fn heartbeat(&mut self, key: &str) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/heartbeats/{}", key).as_str());
-        Box::new(self)
-    }
+pub trait SupportHoursGetApiResource {
+    fn support_hours(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn support_hour(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
-impl UserGetApiResource for GetRequestBuilder<'_> {
+pub trait SupportHoursPostApiResource {
+    fn support_hour(&mut self, entity: &SupportHours) -> Box<&dyn BaseRequestExecutor>;
+    fn support_hour_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
 
-    fn users(&mut self) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path("/users");
-        Box::new(self)
-    }
+pub trait SupportHoursPutApiResource {
+    fn support_hour(&mut self, id: i64, entity: &SupportHours) -> Box<&dyn BaseRequestExecutor>;
+    fn support_hour_raw(&mut self, id: i64, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
 
-    fn user(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/users/{}", id).as_str());
-        Box::new(self)
-    }
+pub trait SupportHoursDeleteApiResource {
+    fn support_hour(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
-impl ScheduleGetApiResource for GetRequestBuilder<'_> {
+/* ### METRICS ### */
 
-    fn schedules(&mut self) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path("/schedules");
-        Box::new(self)
-    }
+pub trait MetricGetApiResource {
+    fn metrics(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn metric(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
 
-    fn schedule(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/schedules/{}", id).as_str());
-        Box::new(self)
-    }
+pub trait MetricPostApiResource {
+    fn metric(&mut self, entity: &Metric) -> Box<&dyn BaseRequestExecutor>;
+    fn metric_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+    fn metric_data_point(&mut self, id: i64, data_point: &MetricDataPoint) -> Box<&dyn BaseRequestExecutor>;
+    fn metric_data_points(&mut self, id: i64, data_points: &[MetricDataPoint]) -> Box<&dyn BaseRequestExecutor>;
+}
 
-    fn schedule_shifts(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/schedules/{}/shifts", id).as_str());
-        Box::new(self)
-    }
+pub trait MetricPutApiResource {
+    fn metric(&mut self, id: i64, entity: &Metric) -> Box<&dyn BaseRequestExecutor>;
+    fn metric_raw(&mut self, id: i64, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
 }
 
-impl AlertGetApiResource for GetRequestBuilder<'_> {
+pub trait MetricDeleteApiResource {
+    fn metric(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
 
-    fn alerts(&mut self) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path("/alerts");
-        Box::new(self)
-    }
+/* ### METRIC DATA SOURCES ### */
 
-    fn alert(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/alerts/{}", id).as_str());
-        Box::new(self)
-    }
+pub trait MetricDataSourceGetApiResource {
+    fn metric_data_sources(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn metric_data_source(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
 }
 
-impl IncidentGetApiResource for GetRequestBuilder<'_> {
+pub trait MetricDataSourcePostApiResource {
+    fn metric_data_source(&mut self, entity: &MetricDataSource) -> Box<&dyn BaseRequestExecutor>;
+    fn metric_data_source_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
+
+pub trait MetricDataSourcePutApiResource {
+    fn metric_data_source(&mut self, id: i64, entity: &MetricDataSource) -> Box<&dyn BaseRequestExecutor>;
+    fn metric_data_source_raw(&mut self, id: i64, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
+
+pub trait MetricDataSourceDeleteApiResource {
+    fn metric_data_source(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
+
+/* ### DEPLOYMENT PIPELINES ### */
+
+pub trait DeploymentPipelineGetApiResource {
+    fn deployment_pipelines(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn deployment_pipeline(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
+
+pub trait DeploymentPipelinePostApiResource {
+    fn deployment_pipeline(&mut self, entity: &DeploymentPipeline) -> Box<&dyn BaseRequestExecutor>;
+    fn deployment_pipeline_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
+
+pub trait DeploymentPipelinePutApiResource {
+    fn deployment_pipeline(&mut self, id: i64, entity: &DeploymentPipeline) -> Box<&dyn BaseRequestExecutor>;
+    fn deployment_pipeline_raw(&mut self, id: i64, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
+
+pub trait DeploymentPipelineDeleteApiResource {
+    fn deployment_pipeline(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
+
+/* ### CALL FLOWS ### */
+
+pub trait CallFlowGetApiResource {
+    fn call_flows(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn call_flow(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+    fn call_flow_numbers(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn call_flow_number(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
+
+pub trait CallFlowPostApiResource {
+    fn call_flow(&mut self, entity: &CallFlow) -> Box<&dyn BaseRequestExecutor>;
+    fn call_flow_raw(&mut self, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
+
+pub trait CallFlowPutApiResource {
+    fn call_flow(&mut self, id: i64, entity: &CallFlow) -> Box<&dyn BaseRequestExecutor>;
+    fn call_flow_raw(&mut self, id: i64, entity: &serde_json::Value) -> Box<&dyn BaseRequestExecutor>;
+}
+
+pub trait CallFlowDeleteApiResource {
+    fn call_flow(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor>;
+}
+
+/* ### AUDIT LOGS ### */
+
+pub trait AuditLogGetApiResource {
+    fn audit_logs(&mut self) -> Box<&dyn BaseRequestExecutor>;
+}
+
+/* ### REPORTS ### */
+
+pub trait ReportGetApiResource {
+    fn alert_report(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn mean_time_report(&mut self) -> Box<&dyn BaseRequestExecutor>;
+    fn notification_report(&mut self) -> Box<&dyn BaseRequestExecutor>;
+}
+
+/* ### GET ### */
+
+#[derive(Debug, Clone)]
+pub struct GetRequestBuilder<'a> {
+    builder: BaseRequestBuilder<'a>,
+}
+
+impl<'a> GetRequestBuilder<'a> {
+
+    pub fn new(_ilert: &'a ILert) -> GetRequestBuilder<'a> {
+        GetRequestBuilder {
+            builder: BaseRequestBuilder::new(_ilert),
+        }
+    }
+
+    /// Parses the response body as json regardless of its content type.
+    pub fn force_json(mut self) -> Self {
+        self.builder.force_json = true;
+        self
+    }
+
+    pub fn skip(mut self, start_index: i64) -> Self {
+        self.builder.start_index = Some(start_index);
+        self
+    }
+
+    pub fn limit(mut self, max_results: i32) -> Self {
+        self.builder.max_results = Some(max_results);
+        self
+    }
+
+    pub fn filter(mut self, key: &str, val: &str) -> Self {
+        self.builder.add_filter(key, val);
+        self
+    }
+
+    pub fn filter_by(mut self, filter: &dyn ILertFilter) -> Self {
+        for (key, val) in filter.to_query() {
+            self.builder.add_filter(key.as_str(), val.as_str());
+        }
+        self
+    }
+}
+
+impl BaseRequestExecutor for GetRequestBuilder<'_> {
+
+    fn execute(&self) -> ILertResult<BaseRequestResult> {
+        execute_request(&self.builder, Method::GET)
+    }
+}
+
+impl HeartbeatApiResource for GetRequestBuilder<'_> {
+
+    fn heartbeat(&mut self, key: &str) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/heartbeats/{key}", &[&key]);
+        Box::new(self)
+    }
+}
+
+impl UserGetApiResource for GetRequestBuilder<'_> {
+
+    fn users(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/users");
+        Box::new(self)
+    }
+
+    fn user(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/users/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl ScheduleGetApiResource for GetRequestBuilder<'_> {
+
+    fn schedules(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/schedules");
+        Box::new(self)
+    }
+
+    fn schedule(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/schedules/{id}", &[&id]);
+        Box::new(self)
+    }
+
+    fn schedule_shifts(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/schedules/{id}/shifts", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl AlertGetApiResource for GetRequestBuilder<'_> {
+
+    fn alerts(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/alerts");
+        Box::new(self)
+    }
+
+    fn alert(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alerts/{id}", &[&id]);
+        Box::new(self)
+    }
+
+    fn alert_log_entries(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alerts/{id}/log-entries", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl IncidentGetApiResource for GetRequestBuilder<'_> {
+
+    fn incidents(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/incidents");
+        Box::new(self)
+    }
+
+    fn incident(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/incidents/{id}", &[&id]);
+        Box::new(self)
+    }
+
+    fn incident_subscribers(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/incidents/{id}/subscribers", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl ServiceGetApiResource for GetRequestBuilder<'_> {
+
+    fn services(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/services");
+        Box::new(self)
+    }
+
+    fn service(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/services/{id}", &[&id]);
+        Box::new(self)
+    }
+
+    fn service_subscribers(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/services/{id}/subscribers", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl MaintenanceWindowGetApiResource for GetRequestBuilder<'_> {
+
+    fn maintenance_windows(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/maintenance-windows");
+        Box::new(self)
+    }
+
+    fn maintenance_window(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/maintenance-windows/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl StatusPageGetApiResource for GetRequestBuilder<'_> {
+
+    fn status_pages(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/status-pages");
+        Box::new(self)
+    }
+
+    fn status_page(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}", &[&id]);
+        Box::new(self)
+    }
+
+    fn status_page_groups(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}/groups", &[&id]);
+        Box::new(self)
+    }
+
+    fn status_page_group(&mut self, id: i64, group_id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}/groups/{group_id}", &[&id, &group_id]);
+        Box::new(self)
+    }
+
+    fn status_page_subscribers(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}/subscribers", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl ConnectorGetApiResource for GetRequestBuilder<'_> {
+
+    fn connectors(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/connectors");
+        Box::new(self)
+    }
+
+    fn connector(&mut self, id: &str) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/connectors/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl AlertActionGetApiResource for GetRequestBuilder<'_> {
+
+    fn alert_actions(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/alert-actions");
+        Box::new(self)
+    }
+
+    fn alert_action(&mut self, id: &str) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alert-actions/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl SupportHoursGetApiResource for GetRequestBuilder<'_> {
+
+    fn support_hours(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/support-hours");
+        Box::new(self)
+    }
+
+    fn support_hour(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/support-hours/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl MetricGetApiResource for GetRequestBuilder<'_> {
+
+    fn metrics(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/metrics");
+        Box::new(self)
+    }
+
+    fn metric(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metrics/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl MetricDataSourceGetApiResource for GetRequestBuilder<'_> {
+
+    fn metric_data_sources(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/metric-data-sources");
+        Box::new(self)
+    }
+
+    fn metric_data_source(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metric-data-sources/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl DeploymentPipelineGetApiResource for GetRequestBuilder<'_> {
+
+    fn deployment_pipelines(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/deployment-pipelines");
+        Box::new(self)
+    }
+
+    fn deployment_pipeline(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/deployment-pipelines/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl CallFlowGetApiResource for GetRequestBuilder<'_> {
+
+    fn call_flows(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/call-flows");
+        Box::new(self)
+    }
+
+    fn call_flow(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/call-flows/{id}", &[&id]);
+        Box::new(self)
+    }
+
+    fn call_flow_numbers(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/call-flow-numbers");
+        Box::new(self)
+    }
+
+    fn call_flow_number(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/call-flow-numbers/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl AuditLogGetApiResource for GetRequestBuilder<'_> {
+
+    fn audit_logs(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/audit-logs");
+        Box::new(self)
+    }
+}
+
+impl ReportGetApiResource for GetRequestBuilder<'_> {
+
+    fn alert_report(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/reports/alerts");
+        Box::new(self)
+    }
+
+    fn mean_time_report(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/reports/mean-times");
+        Box::new(self)
+    }
+
+    fn notification_report(&mut self) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/reports/notifications");
+        Box::new(self)
+    }
+}
+
+/* ### POST ### */
+
+#[derive(Debug, Clone)]
+pub struct PostRequestBuilder<'a> {
+    builder: BaseRequestBuilder<'a>,
+}
+
+impl<'a> PostRequestBuilder<'a> {
+
+    pub fn new(_ilert: &'a ILert, body: &str) -> PostRequestBuilder<'a> {
+        PostRequestBuilder {
+            builder: BaseRequestBuilder::new(_ilert),
+        }
+    }
+
+    /// Parses the response body as json regardless of its content type.
+    pub fn force_json(mut self) -> Self {
+        self.builder.force_json = true;
+        self
+    }
+}
+
+impl BaseRequestExecutor for PostRequestBuilder<'_> {
+
+    fn execute(&self) -> ILertResult<BaseRequestResult> {
+        execute_request(&self.builder, Method::POST)
+    }
+}
+
+impl EventApiResource for PostRequestBuilder<'_> {
+
+    fn event(&mut self, api_key: &str, event_type: ILertEventType, summary: Option<String>, alert_key: Option<String>) -> Box<&dyn BaseRequestExecutor> {
+
+        let json_body = json!({
+            "apiKey": api_key,
+            "eventType": event_type.as_str(),
+            "summary": summary,
+            "alertKey": alert_key
+        });
+
+        self.builder.set_path("/events");
+        self.builder.set_body(json_body.to_string().as_str());
+        Box::new(self)
+    }
+
+    fn event_with_details(&mut self, api_key: &str, event_type: ILertEventType, summary: Option<String>,
+                          alert_key: Option<String>, details: Option<String>, priority: Option<ILertPriority>, images: Option<Vec<EventImage>>,
+        links: Option<Vec<EventLink>>, custom_details: Option<serde_json::Value>, routing_key: Option<String>) -> Box<&dyn BaseRequestExecutor> {
+
+        let priority = priority.map(|e_val| e_val.as_str().to_string());
+
+        let json_body = json!({
+            "apiKey": api_key,
+            "eventType": event_type.as_str(),
+            "summary": summary,
+            "alertKey": alert_key,
+            "details": details,
+            "priority": priority,
+            "images": images,
+            "links": links,
+            "customDetails": custom_details,
+            "routingKey": routing_key
+        });
+
+        self.builder.set_path("/events");
+        self.builder.set_body(json_body.to_string().as_str());
+        Box::new(self)
+    }
+
+    fn event_with_comment(&mut self, api_key: &str, alert_key: Option<String>, comments: Option<Vec<EventComment>>) -> Box<&dyn BaseRequestExecutor> {
+
+        let json_body = json!({
+            "apiKey": api_key,
+            "eventType": ILertEventType::COMMENT.as_str(),
+            "alertKey": alert_key,
+            "comments": comments,
+        });
+
+        self.builder.set_path("/events");
+        self.builder.set_body(json_body.to_string().as_str());
+        Box::new(self)
+    }
+}
+
+impl DeploymentEventApiResource for PostRequestBuilder<'_> {
+
+    fn deployment_event(&mut self, integration_key: &str, event: &DeploymentEvent) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/deployment-events/{integration_key}", &[&integration_key]);
+        self.builder.set_entity_body(event);
+        Box::new(self)
+    }
+}
+
+impl IncidentPostApiResource for PostRequestBuilder<'_> {
+
+    fn incident_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/incidents");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+
+    fn incident_subscribers(&mut self, id: i64, subscribers: &[Subscriber]) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/incidents/{id}/subscribers", &[&id]);
+        self.builder.set_entity_body(&subscribers);
+        Box::new(self)
+    }
+}
+
+impl ServicePostApiResource for PostRequestBuilder<'_> {
+
+    fn service_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/services");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+
+    fn service_subscribers(&mut self, id: i64, subscribers: &[Subscriber]) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/services/{id}/subscribers", &[&id]);
+        self.builder.set_entity_body(&subscribers);
+        Box::new(self)
+    }
+}
+
+impl MaintenanceWindowPostApiResource for PostRequestBuilder<'_> {
+
+    fn maintenance_window(&mut self, entity: &MaintenanceWindow) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/maintenance-windows");
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn maintenance_window_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/maintenance-windows");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+}
+
+impl StatusPagePostApiResource for PostRequestBuilder<'_> {
+
+    fn status_page(&mut self, entity: &StatusPage) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/status-pages");
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn status_page_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/status-pages");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+
+    fn status_page_group(&mut self, id: i64, entity: &StatusPageGroup) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}/groups", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn status_page_subscribers(&mut self, id: i64, subscribers: &[Subscriber]) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}/subscribers", &[&id]);
+        self.builder.set_entity_body(&subscribers);
+        Box::new(self)
+    }
+}
+
+impl ConnectorPostApiResource for PostRequestBuilder<'_> {
+
+    fn connector(&mut self, entity: &Connector) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/connectors");
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn connector_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/connectors");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+}
+
+impl AlertActionPostApiResource for PostRequestBuilder<'_> {
+
+    fn alert_action(&mut self, entity: &AlertAction) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/alert-actions");
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn alert_action_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/alert-actions");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+}
+
+impl SupportHoursPostApiResource for PostRequestBuilder<'_> {
+
+    fn support_hour(&mut self, entity: &SupportHours) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/support-hours");
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn support_hour_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/support-hours");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+}
+
+impl MetricPostApiResource for PostRequestBuilder<'_> {
+
+    fn metric(&mut self, entity: &Metric) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/metrics");
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn metric_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/metrics");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+
+    fn metric_data_point(&mut self, id: i64, data_point: &MetricDataPoint) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metrics/{id}/series", &[&id]);
+        self.builder.set_entity_body(data_point);
+        Box::new(self)
+    }
+
+    fn metric_data_points(&mut self, id: i64, data_points: &[MetricDataPoint]) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metrics/{id}/series/batch", &[&id]);
+        self.builder.set_entity_body(&data_points);
+        Box::new(self)
+    }
+}
 
-    fn incidents(&mut self) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path("/incidents");
+impl MetricDataSourcePostApiResource for PostRequestBuilder<'_> {
+
+    fn metric_data_source(&mut self, entity: &MetricDataSource) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/metric-data-sources");
+        self.builder.set_entity_body(entity);
         Box::new(self)
     }
 
-    fn incident(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/incidents/{}", id).as_str());
+    fn metric_data_source_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/metric-data-sources");
+        self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
 }
 
-impl ServiceGetApiResource for GetRequestBuilder<'_> {
+impl DeploymentPipelinePostApiResource for PostRequestBuilder<'_> {
 
-    fn services(&mut self) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path("/services");
+    fn deployment_pipeline(&mut self, entity: &DeploymentPipeline) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/deployment-pipelines");
+        self.builder.set_entity_body(entity);
         Box::new(self)
     }
 
-    fn service(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/services/{}", id).as_str());
+    fn deployment_pipeline_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/deployment-pipelines");
+        self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
 }
 
-/* ### POST ### */
+impl CallFlowPostApiResource for PostRequestBuilder<'_> {
+
+    fn call_flow(&mut self, entity: &CallFlow) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/call-flows");
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn call_flow_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path("/call-flows");
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+}
+
+/* ### PUT ### */
 
 #[derive(Debug, Clone)]
-pub struct PostRequestBuilder<'a> {
+pub struct PutRequestBuilder<'a> {
     builder: BaseRequestBuilder<'a>,
 }
 
-impl<'a> PostRequestBuilder<'a> {
+impl<'a> PutRequestBuilder<'a> {
 
-    pub fn new(_ilert: &'a ILert, body: &str) -> PostRequestBuilder<'a> {
-        PostRequestBuilder {
+    pub fn new(_ilert: &'a ILert, body: &str) -> PutRequestBuilder<'a> {
+        PutRequestBuilder {
             builder: BaseRequestBuilder::new(_ilert),
         }
     }
+
+    /// Parses the response body as json regardless of its content type.
+    pub fn force_json(mut self) -> Self {
+        self.builder.force_json = true;
+        self
+    }
 }
 
-impl BaseRequestExecutor for PostRequestBuilder<'_> {
+impl BaseRequestExecutor for PutRequestBuilder<'_> {
 
     fn execute(&self) -> ILertResult<BaseRequestResult> {
-
-        let options_result = prepare_generic_request_builder(&self.builder);
-        if options_result.is_err() {
-            return Err(options_result.unwrap_err());
-        }
-        let options = options_result.unwrap();
-
-        if options.url.is_none() {
-            return Err(ILertError::new("Failed to build url."));
-        }
-        let url = options.url.unwrap();
-
-        let mut response_result = self.builder._ilert.http_client
-            .post(url.as_str())
-            .headers(options.headers);
-
-        response_result = match options.body {
-            Some(value) => response_result.body(value),
-            None => response_result,
-        };
-
-        let mut response = match response_result.send() {
-            Ok(value) => value,
-            Err(err) => {
-                return Err(ILertError::new(err.to_string().as_str()));
-            },
-        };
-
-        let response_status = response.status().clone();
-        let response_headers = response.headers().clone();
-
-        let body_raw = match response.text() {
-            Ok(value) => Some(value),
-            Err(_) => None,
-        };
-
-        let body_json = match body_raw.clone() {
-            Some(raw_value) =>
-                match response_headers.get("content-type") {
-                    Some(ct_value) =>
-                        if ct_value.eq(&"application/json") {
-                            let parsed_json_result = serde_json::from_str(raw_value.as_str());
-                            match parsed_json_result {
-                                Ok(parsed_json) => Some(parsed_json),
-                                Err(err) => {
-                                    return Err(ILertError::new(err.to_string().as_str()));
-                                },
-                            }
-                        } else {
-                            None
-                        },
-                    None => None,
-                },
-            None => None,
-        };
-
-        Ok(BaseRequestResult::new(
-            url,
-            response_status,
-            response_headers,
-            body_raw,
-            body_json,
-        ))
+        execute_request(&self.builder, Method::PUT)
     }
 }
 
-impl EventApiResource for PostRequestBuilder<'_> {
-
-    fn event(&mut self, api_key: &str, event_type: ILertEventType, summary: Option<String>, alert_key: Option<String>) -> Box<&dyn BaseRequestExecutor> {
-
-        let json_body = json!({
-            "apiKey": api_key,
-            "eventType": event_type.as_str(),
-            "summary": summary,
-            "alertKey": alert_key
-        });
+impl AlertPutApiResource for PutRequestBuilder<'_> {
 
-        self.builder.set_path("/events");
-        self.builder.set_body(json_body.to_string().as_str());
+    fn alert_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alerts/{id}", &[&id]);
+        self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
 
-    fn event_with_details(&mut self, api_key: &str, event_type: ILertEventType, summary: Option<String>,
-                          alert_key: Option<String>, details: Option<String>, priority: Option<ILertPriority>, images: Option<Vec<EventImage>>,
-        links: Option<Vec<EventLink>>, custom_details: Option<serde_json::Value>, routing_key: Option<String>) -> Box<&dyn BaseRequestExecutor> {
-
-        let priority = match priority {
-            Some(e_val) => Some(e_val.as_str().to_string()),
-            None => None
-        };
-
-        let json_body = json!({
-            "apiKey": api_key,
-            "eventType": event_type.as_str(),
-            "summary": summary,
-            "alertKey": alert_key,
-            "details": details,
-            "priority": priority,
-            "images": images,
-            "links": links,
-            "customDetails": custom_details,
-            "routingKey": routing_key
-        });
-
-        self.builder.set_path("/events");
-        self.builder.set_body(json_body.to_string().as_str());
+    fn accept_alert(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alerts/{id}/accept", &[&id]);
         Box::new(self)
     }
 
-    fn event_with_comment(&mut self, api_key: &str, alert_key: Option<String>, comments: Option<Vec<EventComment>>) -> Box<&dyn BaseRequestExecutor> {
-
-        let json_body = json!({
-            "apiKey": api_key,
-            "eventType": ILertEventType::COMMENT.as_str(),
-            "alertKey": alert_key,
-            "comments": comments,
-        });
-
-        self.builder.set_path("/events");
-        self.builder.set_body(json_body.to_string().as_str());
+    fn resolve_alert(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alerts/{id}/resolve", &[&id]);
         Box::new(self)
     }
 }
 
-impl IncidentPostApiResource for PostRequestBuilder<'_> {
+impl IncidentPutApiResource for PutRequestBuilder<'_> {
 
-    fn incident_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path("/incidents");
+    fn incident_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/incidents/{id}", &[&id]);
         self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
 }
 
-impl ServicePostApiResource for PostRequestBuilder<'_> {
+impl ServicePutApiResource for PutRequestBuilder<'_> {
 
-    fn service_raw(&mut self, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path("/services");
+    fn service_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/services/{id}", &[&id]);
         self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
 }
 
-/* ### PUT ### */
+impl MaintenanceWindowPutApiResource for PutRequestBuilder<'_> {
 
-#[derive(Debug, Clone)]
-pub struct PutRequestBuilder<'a> {
-    builder: BaseRequestBuilder<'a>,
+    fn maintenance_window(&mut self, id: i64, entity: &MaintenanceWindow) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/maintenance-windows/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn maintenance_window_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/maintenance-windows/{id}", &[&id]);
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
 }
 
-impl<'a> PutRequestBuilder<'a> {
+impl StatusPagePutApiResource for PutRequestBuilder<'_> {
 
-    pub fn new(_ilert: &'a ILert, body: &str) -> PutRequestBuilder<'a> {
-        PutRequestBuilder {
-            builder: BaseRequestBuilder::new(_ilert),
-        }
+    fn status_page(&mut self, id: i64, entity: &StatusPage) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
     }
-}
 
-impl BaseRequestExecutor for PutRequestBuilder<'_> {
+    fn status_page_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}", &[&id]);
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
 
-    fn execute(&self) -> ILertResult<BaseRequestResult> {
+    fn status_page_group(&mut self, id: i64, group_id: i64, entity: &StatusPageGroup) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}/groups/{group_id}", &[&id, &group_id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+}
 
-        let options_result = prepare_generic_request_builder(&self.builder);
-        if options_result.is_err() {
-            return Err(options_result.unwrap_err());
-        }
-        let options = options_result.unwrap();
+impl ConnectorPutApiResource for PutRequestBuilder<'_> {
 
-        if options.url.is_none() {
-            return Err(ILertError::new("Failed to build url."));
-        }
-        let url = options.url.unwrap();
+    fn connector(&mut self, id: &str, entity: &Connector) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/connectors/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
 
-        let mut response_result = self.builder._ilert.http_client
-            .put(url.as_str())
-            .headers(options.headers);
+    fn connector_raw(&mut self, id: &str, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/connectors/{id}", &[&id]);
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+}
 
-        response_result = match options.body {
-            Some(value) => response_result.body(value),
-            None => response_result,
-        };
+impl AlertActionPutApiResource for PutRequestBuilder<'_> {
 
-        let mut response = match response_result.send() {
-            Ok(value) => value,
-            Err(err) => {
-                return Err(ILertError::new(err.to_string().as_str()));
-            },
-        };
+    fn alert_action(&mut self, id: &str, entity: &AlertAction) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alert-actions/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
 
-        let response_status = response.status().clone();
-        let response_headers = response.headers().clone();
+    fn alert_action_raw(&mut self, id: &str, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alert-actions/{id}", &[&id]);
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
+    }
+}
 
-        let body_raw = match response.text() {
-            Ok(value) => Some(value),
-            Err(_) => None,
-        };
+impl SupportHoursPutApiResource for PutRequestBuilder<'_> {
 
-        let body_json = match body_raw.clone() {
-            Some(raw_value) =>
-                match response_headers.get("content-type") {
-                    Some(ct_value) =>
-                        if ct_value.eq(&"application/json") {
-                            let parsed_json_result = serde_json::from_str(raw_value.as_str());
-                            match parsed_json_result {
-                                Ok(parsed_json) => Some(parsed_json),
-                                Err(err) => {
-                                    return Err(ILertError::new(err.to_string().as_str()));
-                                },
-                            }
-                        } else {
-                            None
-                        },
-                    None => None,
-                },
-            None => None,
-        };
+    fn support_hour(&mut self, id: i64, entity: &SupportHours) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/support-hours/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
 
-        Ok(BaseRequestResult::new(
-            url,
-            response_status,
-            response_headers,
-            body_raw,
-            body_json,
-        ))
+    fn support_hour_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/support-hours/{id}", &[&id]);
+        self.builder.set_body(entity.to_string().as_str());
+        Box::new(self)
     }
 }
 
-impl AlertPutApiResource for PutRequestBuilder<'_> {
+impl MetricPutApiResource for PutRequestBuilder<'_> {
 
-    fn alert_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/alerts/{}", id).as_str());
+    fn metric(&mut self, id: i64, entity: &Metric) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metrics/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn metric_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metrics/{id}", &[&id]);
         self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
+}
 
-    fn accept_alert(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/alerts/{}/accept", id).as_str());
+impl MetricDataSourcePutApiResource for PutRequestBuilder<'_> {
+
+    fn metric_data_source(&mut self, id: i64, entity: &MetricDataSource) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metric-data-sources/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
         Box::new(self)
     }
 
-    fn resolve_alert(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/alerts/{}/resolve", id).as_str());
+    fn metric_data_source_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metric-data-sources/{id}", &[&id]);
+        self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
 }
 
-impl IncidentPutApiResource for PutRequestBuilder<'_> {
+impl DeploymentPipelinePutApiResource for PutRequestBuilder<'_> {
 
-    fn incident_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/incidents/{}", id).as_str());
+    fn deployment_pipeline(&mut self, id: i64, entity: &DeploymentPipeline) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/deployment-pipelines/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn deployment_pipeline_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/deployment-pipelines/{id}", &[&id]);
         self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
 }
 
-impl ServicePutApiResource for PutRequestBuilder<'_> {
+impl CallFlowPutApiResource for PutRequestBuilder<'_> {
 
-    fn service_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/services/{}", id).as_str());
+    fn call_flow(&mut self, id: i64, entity: &CallFlow) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/call-flows/{id}", &[&id]);
+        self.builder.set_entity_body(entity);
+        Box::new(self)
+    }
+
+    fn call_flow_raw(&mut self, id: i64, entity: &Value) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/call-flows/{id}", &[&id]);
         self.builder.set_body(entity.to_string().as_str());
         Box::new(self)
     }
@@ -801,85 +1877,125 @@ impl<'a> DeleteRequestBuilder<'a> {
             builder: BaseRequestBuilder::new(_ilert),
         }
     }
+
+    /// Parses the response body as json regardless of its content type.
+    pub fn force_json(mut self) -> Self {
+        self.builder.force_json = true;
+        self
+    }
 }
 
 impl BaseRequestExecutor for DeleteRequestBuilder<'_> {
 
     fn execute(&self) -> ILertResult<BaseRequestResult> {
+        execute_request(&self.builder, Method::DELETE)
+    }
+}
 
-        let options_result = prepare_generic_request_builder(&self.builder);
-        if options_result.is_err() {
-            return Err(options_result.unwrap_err());
-        }
-        let options = options_result.unwrap();
+impl IncidentDeleteApiResource for DeleteRequestBuilder<'_> {
 
-        if options.url.is_none() {
-            return Err(ILertError::new("Failed to build url."));
-        }
-        let url = options.url.unwrap();
+    fn incident(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/incidents/{id}", &[&id]);
+        Box::new(self)
+    }
 
-        let response_result = self.builder._ilert.http_client
-            .get(url.as_str())
-            .headers(options.headers)
-            .send();
+    fn incident_subscriber(&mut self, id: i64, subscriber_id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/incidents/{id}/subscribers/{subscriber_id}", &[&id, &subscriber_id]);
+        Box::new(self)
+    }
+}
 
-        let mut response = match response_result {
-            Ok(value) => value,
-            Err(err) => {
-                return Err(ILertError::new(err.to_string().as_str()));
-            },
-        };
+impl ServiceDeleteApiResource for DeleteRequestBuilder<'_> {
 
-        let response_status = response.status().clone();
-        let response_headers = response.headers().clone();
+    fn service(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/services/{id}", &[&id]);
+        Box::new(self)
+    }
 
-        let body_raw = match response.text() {
-            Ok(value) => Some(value.clone()),
-            Err(_) => None,
-        };
+    fn service_subscriber(&mut self, id: i64, subscriber_id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/services/{id}/subscribers/{subscriber_id}", &[&id, &subscriber_id]);
+        Box::new(self)
+    }
+}
 
-        let body_json = match body_raw.clone() {
-            Some(raw_value) =>
-                match response_headers.get("content-type") {
-                    Some(ct_value) =>
-                        if ct_value.eq(&"application/json") {
-                            let parsed_json_result = serde_json::from_str(raw_value.as_str());
-                            match parsed_json_result {
-                                Ok(parsed_json) => Some(parsed_json),
-                                Err(err) => {
-                                    return Err(ILertError::new(err.to_string().as_str()));
-                                },
-                            }
-                        } else {
-                            None
-                        },
-                    None => None,
-                },
-            None => None,
-        };
+impl MaintenanceWindowDeleteApiResource for DeleteRequestBuilder<'_> {
 
-        Ok(BaseRequestResult::new(
-            url,
-            response_status,
-            response_headers,
-            body_raw,
-            body_json,
-        ))
+    fn maintenance_window(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/maintenance-windows/{id}", &[&id]);
+        Box::new(self)
     }
 }
 
-impl IncidentDeleteApiResource for DeleteRequestBuilder<'_> {
+impl StatusPageDeleteApiResource for DeleteRequestBuilder<'_> {
 
-    fn incident(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/incidents/{}", id).as_str());
+    fn status_page(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}", &[&id]);
+        Box::new(self)
+    }
+
+    fn status_page_group(&mut self, id: i64, group_id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}/groups/{group_id}", &[&id, &group_id]);
+        Box::new(self)
+    }
+
+    fn status_page_subscriber(&mut self, id: i64, subscriber_id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/status-pages/{id}/subscribers/{subscriber_id}", &[&id, &subscriber_id]);
         Box::new(self)
     }
 }
 
-impl ServiceDeleteApiResource for DeleteRequestBuilder<'_> {
+impl ConnectorDeleteApiResource for DeleteRequestBuilder<'_> {
 
-    fn service(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
-        self.builder.set_path(format!("/services/{}", id).as_str());
+    fn connector(&mut self, id: &str) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/connectors/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl AlertActionDeleteApiResource for DeleteRequestBuilder<'_> {
+
+    fn alert_action(&mut self, id: &str) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/alert-actions/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl SupportHoursDeleteApiResource for DeleteRequestBuilder<'_> {
+
+    fn support_hour(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/support-hours/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl MetricDeleteApiResource for DeleteRequestBuilder<'_> {
+
+    fn metric(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metrics/{id}", &[&id]);
         Box::new(self)
     }
-}
\ No newline at end of file
+}
+
+impl MetricDataSourceDeleteApiResource for DeleteRequestBuilder<'_> {
+
+    fn metric_data_source(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/metric-data-sources/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl DeploymentPipelineDeleteApiResource for DeleteRequestBuilder<'_> {
+
+    fn deployment_pipeline(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/deployment-pipelines/{id}", &[&id]);
+        Box::new(self)
+    }
+}
+
+impl CallFlowDeleteApiResource for DeleteRequestBuilder<'_> {
+
+    fn call_flow(&mut self, id: i64) -> Box<&dyn BaseRequestExecutor> {
+        self.builder.set_path_template("/call-flows/{id}", &[&id]);
+        Box::new(self)
+    }
+}
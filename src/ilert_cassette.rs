@@ -0,0 +1,299 @@
+//! Record/replay of HTTP interactions for deterministic tests.
+//!
+//! `CassetteTransport` wraps another `HttpTransport`: in `RECORD` mode every request is sent and
+//! recorded to a JSON cassette file with tokens, passwords and keys scrubbed, in `REPLAY` mode no
+//! request leaves the process and responses are served from the cassette, matched on method,
+//! path (incl. query) and body.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use http::StatusCode;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use log::error;
+
+use crate::ilert_error::{ILertResult, ILertError};
+use crate::ilert_redact::{is_sensitive_header, redact_body, redact_url};
+use crate::ilert_transport::{HttpTransport, TransportRequest, TransportResponse};
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CassetteMode {
+    RECORD,
+    REPLAY,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all="camelCase")]
+pub struct CassetteRequest {
+    pub method: String,
+    /// Path and query relative to the host, with keys in the path and sensitive query parameters scrubbed.
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all="camelCase")]
+pub struct CassetteResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all="camelCase")]
+pub struct Interaction {
+    pub request: CassetteRequest,
+    pub response: CassetteResponse,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all="camelCase")]
+pub struct CassetteFile {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    played: Vec<bool>,
+}
+
+/// A cassette file used by the request pipeline, pass it to `ILertBuilder::cassette()`.
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<CassetteState>,
+}
+
+impl Cassette {
+
+    /// Starts an empty recording that replaces an existing file (use `record_once()` to keep it),
+    /// the file is written on `save()` or when the cassette is dropped with at least one interaction.
+    pub fn record<P: AsRef<Path>>(path: P) -> Cassette {
+        Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::RECORD,
+            state: Mutex::new(CassetteState { interactions: Vec::new(), played: Vec::new() }),
+        }
+    }
+
+    pub fn replay<P: AsRef<Path>>(path: P) -> ILertResult<Cassette> {
+
+        let content = fs::read_to_string(path.as_ref())
+            .map_err(|err| ILertError::new(format!("Failed to read cassette '{}': {}.", path.as_ref().display(), err).as_str()))?;
+        let file: CassetteFile = serde_json::from_str(content.as_str())
+            .map_err(|err| ILertError::new(format!("Invalid cassette '{}': {}.", path.as_ref().display(), err).as_str()))?;
+
+        let played = vec![false; file.interactions.len()];
+        Ok(Cassette {
+            path: path.as_ref().to_path_buf(),
+            mode: CassetteMode::REPLAY,
+            state: Mutex::new(CassetteState { interactions: file.interactions, played }),
+        })
+    }
+
+    /// Replays if the file exists, records otherwise.
+    pub fn record_once<P: AsRef<Path>>(path: P) -> ILertResult<Cassette> {
+        if path.as_ref().exists() {
+            Cassette::replay(path)
+        } else {
+            Ok(Cassette::record(path))
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state.lock()
+            .map(|state| state.interactions.clone())
+            .unwrap_or_default()
+    }
+
+    /// Number of recorded interactions not served yet in `REPLAY` mode.
+    pub fn unplayed(&self) -> usize {
+        self.state.lock()
+            .map(|state| state.played.iter().filter(|played| !**played).count())
+            .unwrap_or(0)
+    }
+
+    /// Scrubbed request as stored in (and matched against) the cassette.
+    pub fn to_cassette_request(request: &TransportRequest) -> CassetteRequest {
+
+        let path = match url::Url::parse(request.url.as_str()) {
+            Ok(url) => match url.query() {
+                Some(query) => format!("{}?{}", url.path(), query),
+                None => url.path().to_string(),
+            },
+            Err(_) => request.url.clone(),
+        };
+
+        let body = request.body.as_ref()
+            .filter(|body| !body.is_empty())
+            .map(|body| redact_body(body.as_str()));
+
+        CassetteRequest {
+            method: request.method.as_str().to_string(),
+            path: redact_url(path.as_str()),
+            body,
+        }
+    }
+
+    /// Serves the first not yet played interaction matching the request.
+    pub fn play(&self, request: &CassetteRequest) -> ILertResult<CassetteResponse> {
+
+        let mut state = self.state.lock()
+            .map_err(|_| ILertError::new("Cassette state is poisoned."))?;
+
+        let position = state.interactions.iter().enumerate()
+            .position(|(index, interaction)| !state.played[index] && request_matches(&interaction.request, request));
+
+        match position {
+            Some(index) => {
+                state.played[index] = true;
+                Ok(state.interactions[index].response.clone())
+            },
+            None => Err(ILertError::new(format!("No recorded interaction for {} {} in cassette '{}'.",
+                request.method, request.path, self.path.display()).as_str())),
+        }
+    }
+
+    /// Appends a scrubbed interaction, written to the file on `save()` or drop.
+    pub fn add(&self, request: CassetteRequest, response: &TransportResponse) -> ILertResult<()> {
+
+        let headers = response.headers.iter()
+            .filter(|(name, _)| !is_sensitive_header(name.as_str()))
+            .filter_map(|(name, value)| value.to_str().ok().map(|value| (name.to_string(), value.to_string())))
+            .collect();
+
+        let response = CassetteResponse {
+            status: response.status.as_u16(),
+            headers,
+            body: response.body.as_deref().filter(|body| !body.is_empty()).map(redact_body),
+        };
+
+        let mut state = self.state.lock()
+            .map_err(|_| ILertError::new("Cassette state is poisoned."))?;
+        state.interactions.push(Interaction { request, response });
+        state.played.push(true);
+        Ok(())
+    }
+
+    /// Writes the recorded interactions via a temporary file and rename, so an interrupted run never
+    /// leaves a truncated cassette. No-op in `REPLAY` mode.
+    pub fn save(&self) -> ILertResult<()> {
+
+        if self.mode != CassetteMode::RECORD {
+            return Ok(());
+        }
+
+        let file = CassetteFile { interactions: self.interactions() };
+        let content = serde_json::to_string_pretty(&file)
+            .map_err(|err| ILertError::new(err.to_string().as_str()))?;
+
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        fs::write(&temp_path, content)
+            .and_then(|_| fs::rename(&temp_path, self.path.as_path()))
+            .map_err(|err| ILertError::new(format!("Failed to write cassette '{}': {}.", self.path.display(), err).as_str()))
+    }
+}
+
+// recordings of a panicking (i.e. failing) test are discarded
+impl Drop for Cassette {
+    fn drop(&mut self) {
+        // an unused recording must not replace an existing cassette with an empty one
+        let recorded = self.state.lock().map(|state| !state.interactions.is_empty()).unwrap_or(false);
+        if self.mode == CassetteMode::RECORD && recorded && !std::thread::panicking() {
+            if let Err(err) = self.save() {
+                error!("{}", err);
+            }
+        }
+    }
+}
+
+/// Transport recording to or replaying from a cassette, `inner` is only used while recording.
+#[derive(Debug)]
+pub struct CassetteTransport {
+    cassette: Arc<Cassette>,
+    inner: Arc<dyn HttpTransport>,
+}
+
+impl CassetteTransport {
+
+    pub fn new(cassette: Arc<Cassette>, inner: Arc<dyn HttpTransport>) -> CassetteTransport {
+        CassetteTransport { cassette, inner }
+    }
+
+    pub fn cassette(&self) -> &Cassette {
+        self.cassette.as_ref()
+    }
+}
+
+impl HttpTransport for CassetteTransport {
+    fn execute(&self, request: TransportRequest) -> ILertResult<TransportResponse> {
+
+        let cassette_request = Cassette::to_cassette_request(&request);
+
+        match self.cassette.mode() {
+            CassetteMode::REPLAY => {
+                let response = self.cassette.play(&cassette_request)?;
+                Ok(TransportResponse::new(response.status(), response.header_map(), response.body))
+            },
+            CassetteMode::RECORD => {
+                let response = self.inner.execute(request)?;
+                self.cassette.add(cassette_request, &response)?;
+                Ok(response)
+            },
+        }
+    }
+}
+
+impl CassetteResponse {
+
+    pub fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    pub fn header_map(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in self.headers.iter() {
+            if let (Ok(name), Ok(value)) = (HeaderName::from_bytes(name.as_bytes()), HeaderValue::from_str(value.as_str())) {
+                headers.append(name, value);
+            }
+        }
+        headers
+    }
+}
+
+// json bodies are compared structurally so key order and whitespace do not matter
+fn request_matches(recorded: &CassetteRequest, request: &CassetteRequest) -> bool {
+
+    if recorded.method != request.method || recorded.path != request.path {
+        return false;
+    }
+
+    match (&recorded.body, &request.body) {
+        (None, None) => true,
+        (Some(recorded), Some(body)) => {
+            match (serde_json::from_str::<Value>(recorded), serde_json::from_str::<Value>(body)) {
+                (Ok(recorded), Ok(body)) => recorded == body,
+                _ => recorded == body,
+            }
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,441 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::ilert_builders::{AlertState, IncidentState, ILertPriority, ServiceStatus, UserRole};
+
+/// Typed set of query parameters that can be passed to `GetRequestBuilder::filter_by()`.
+/// The raw `GetRequestBuilder::filter(key, val)` remains available for anything not covered here.
+pub trait ILertFilter {
+    fn to_query(&self) -> Vec<(String, String)>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortOrder {
+    ASC,
+    DESC,
+}
+
+impl SortOrder {
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            SortOrder::ASC => "ASC",
+            SortOrder::DESC => "DESC",
+        }
+    }
+}
+
+fn format_time(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn push_all<T: ToString>(query: &mut Vec<(String, String)>, key: &str, values: &[T]) {
+    for val in values {
+        query.push((key.to_string(), val.to_string()));
+    }
+}
+
+/* ### ALERTS ### */
+
+#[derive(Debug, Clone, Default)]
+pub struct AlertFilter {
+    pub states: Vec<AlertState>,
+    pub alert_sources: Vec<i64>,
+    pub responders: Vec<i64>,
+    pub policies: Vec<i64>,
+    pub priority: Option<ILertPriority>,
+    pub from: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub sort: Option<SortOrder>,
+}
+
+impl AlertFilter {
+
+    pub fn new() -> AlertFilter {
+        AlertFilter::default()
+    }
+
+    pub fn state(mut self, state: AlertState) -> Self {
+        self.states.push(state);
+        self
+    }
+
+    pub fn alert_source(mut self, id: i64) -> Self {
+        self.alert_sources.push(id);
+        self
+    }
+
+    pub fn responder(mut self, id: i64) -> Self {
+        self.responders.push(id);
+        self
+    }
+
+    pub fn policy(mut self, id: i64) -> Self {
+        self.policies.push(id);
+        self
+    }
+
+    pub fn priority(mut self, priority: ILertPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub fn from(mut self, from: DateTime<Utc>) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn sort(mut self, sort: SortOrder) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+impl ILertFilter for AlertFilter {
+
+    fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+
+        for state in &self.states {
+            query.push(("states".to_string(), state.as_str().to_string()));
+        }
+
+        push_all(&mut query, "alert-sources", &self.alert_sources);
+        push_all(&mut query, "responders", &self.responders);
+        push_all(&mut query, "policies", &self.policies);
+
+        if let Some(priority) = &self.priority {
+            query.push(("priority".to_string(), priority.as_str().to_string()));
+        }
+
+        if let Some(from) = &self.from {
+            query.push(("from".to_string(), format_time(from)));
+        }
+
+        if let Some(until) = &self.until {
+            query.push(("until".to_string(), format_time(until)));
+        }
+
+        if let Some(sort) = &self.sort {
+            query.push(("sort".to_string(), sort.as_str().to_string()));
+        }
+
+        query
+    }
+}
+
+/* ### USERS ### */
+
+#[derive(Debug, Clone, Default)]
+pub struct UserFilter {
+    pub roles: Vec<UserRole>,
+}
+
+impl UserFilter {
+
+    pub fn new() -> UserFilter {
+        UserFilter::default()
+    }
+
+    pub fn role(mut self, role: UserRole) -> Self {
+        self.roles.push(role);
+        self
+    }
+}
+
+impl ILertFilter for UserFilter {
+
+    fn to_query(&self) -> Vec<(String, String)> {
+        self.roles.iter()
+            .map(|role| ("role".to_string(), role.as_str().to_string()))
+            .collect()
+    }
+}
+
+/* ### SERVICES ### */
+
+#[derive(Debug, Clone, Default)]
+pub struct ServiceFilter {
+    pub statuses: Vec<ServiceStatus>,
+    pub include_uptime: bool,
+    pub include_incidents: bool,
+}
+
+impl ServiceFilter {
+
+    pub fn new() -> ServiceFilter {
+        ServiceFilter::default()
+    }
+
+    pub fn status(mut self, status: ServiceStatus) -> Self {
+        self.statuses.push(status);
+        self
+    }
+
+    pub fn include_uptime(mut self) -> Self {
+        self.include_uptime = true;
+        self
+    }
+
+    pub fn include_incidents(mut self) -> Self {
+        self.include_incidents = true;
+        self
+    }
+}
+
+impl ILertFilter for ServiceFilter {
+
+    fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+
+        for status in &self.statuses {
+            query.push(("status".to_string(), status.as_str().to_string()));
+        }
+
+        if self.include_uptime {
+            query.push(("include".to_string(), "uptime".to_string()));
+        }
+
+        if self.include_incidents {
+            query.push(("include".to_string(), "incidents".to_string()));
+        }
+
+        query
+    }
+}
+
+/* ### INCIDENTS ### */
+
+#[derive(Debug, Clone, Default)]
+pub struct IncidentFilter {
+    pub states: Vec<IncidentState>,
+    pub services: Vec<i64>,
+    pub from: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl IncidentFilter {
+
+    pub fn new() -> IncidentFilter {
+        IncidentFilter::default()
+    }
+
+    pub fn state(mut self, state: IncidentState) -> Self {
+        self.states.push(state);
+        self
+    }
+
+    pub fn service(mut self, id: i64) -> Self {
+        self.services.push(id);
+        self
+    }
+
+    pub fn from(mut self, from: DateTime<Utc>) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+}
+
+impl ILertFilter for IncidentFilter {
+
+    fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+
+        for state in &self.states {
+            query.push(("states".to_string(), state.as_str().to_string()));
+        }
+
+        push_all(&mut query, "services", &self.services);
+
+        if let Some(from) = &self.from {
+            query.push(("from".to_string(), format_time(from)));
+        }
+
+        if let Some(until) = &self.until {
+            query.push(("until".to_string(), format_time(until)));
+        }
+
+        query
+    }
+}
+
+/* ### AUDIT LOGS ### */
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum AuditLogEntityType {
+    ALERT_SOURCE,
+    ESCALATION_POLICY,
+    SCHEDULE,
+    USER,
+    TEAM,
+    SERVICE,
+    INCIDENT,
+    /// Any entity type not known to this client, only returned in `AuditLogEntry`,
+    /// it is never sent as a filter.
+    #[serde(other)]
+    OTHER,
+}
+
+impl AuditLogEntityType {
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            AuditLogEntityType::ALERT_SOURCE => "ALERT_SOURCE",
+            AuditLogEntityType::ESCALATION_POLICY => "ESCALATION_POLICY",
+            AuditLogEntityType::SCHEDULE => "SCHEDULE",
+            AuditLogEntityType::USER => "USER",
+            AuditLogEntityType::TEAM => "TEAM",
+            AuditLogEntityType::SERVICE => "SERVICE",
+            AuditLogEntityType::INCIDENT => "INCIDENT",
+            AuditLogEntityType::OTHER => "OTHER",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogFilter {
+    pub actors: Vec<i64>,
+    pub entity_types: Vec<AuditLogEntityType>,
+    pub from: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl AuditLogFilter {
+
+    pub fn new() -> AuditLogFilter {
+        AuditLogFilter::default()
+    }
+
+    pub fn actor(mut self, user_id: i64) -> Self {
+        self.actors.push(user_id);
+        self
+    }
+
+    /// Filters by entity type, `AuditLogEntityType::OTHER` matches no known type and is ignored.
+    pub fn entity_type(mut self, entity_type: AuditLogEntityType) -> Self {
+        if entity_type != AuditLogEntityType::OTHER {
+            self.entity_types.push(entity_type);
+        }
+        self
+    }
+
+    pub fn from(mut self, from: DateTime<Utc>) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+}
+
+impl ILertFilter for AuditLogFilter {
+
+    fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+
+        push_all(&mut query, "actors", &self.actors);
+
+        for entity_type in self.entity_types.iter().filter(|entity_type| **entity_type != AuditLogEntityType::OTHER) {
+            query.push(("entity-types".to_string(), entity_type.as_str().to_string()));
+        }
+
+        if let Some(from) = &self.from {
+            query.push(("from".to_string(), format_time(from)));
+        }
+
+        if let Some(until) = &self.until {
+            query.push(("until".to_string(), format_time(until)));
+        }
+
+        query
+    }
+}
+
+/* ### REPORTS ### */
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportInterval {
+    DAY,
+    WEEK,
+    MONTH,
+}
+
+impl ReportInterval {
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReportInterval::DAY => "DAY",
+            ReportInterval::WEEK => "WEEK",
+            ReportInterval::MONTH => "MONTH",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReportFilter {
+    pub from: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub alert_sources: Vec<i64>,
+    pub teams: Vec<i64>,
+    pub interval: Option<ReportInterval>,
+}
+
+impl ReportFilter {
+
+    pub fn new(from: DateTime<Utc>, until: DateTime<Utc>) -> ReportFilter {
+        ReportFilter {
+            from,
+            until,
+            alert_sources: Vec::new(),
+            teams: Vec::new(),
+            interval: None
+        }
+    }
+
+    pub fn alert_source(mut self, id: i64) -> Self {
+        self.alert_sources.push(id);
+        self
+    }
+
+    pub fn team(mut self, id: i64) -> Self {
+        self.teams.push(id);
+        self
+    }
+
+    pub fn interval(mut self, interval: ReportInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+}
+
+impl ILertFilter for ReportFilter {
+
+    fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![
+            ("from".to_string(), format_time(&self.from)),
+            ("until".to_string(), format_time(&self.until)),
+        ];
+
+        push_all(&mut query, "alert-sources", &self.alert_sources);
+        push_all(&mut query, "teams", &self.teams);
+
+        if let Some(interval) = &self.interval {
+            query.push(("interval".to_string(), interval.as_str().to_string()));
+        }
+
+        query
+    }
+}
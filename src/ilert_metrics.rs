@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StatusClass {
+    INFORMATIONAL,
+    SUCCESS,
+    REDIRECTION,
+    CLIENT_ERROR,
+    SERVER_ERROR,
+    TRANSPORT_ERROR,
+}
+
+impl StatusClass {
+
+    pub fn from_status(status: Option<u16>) -> StatusClass {
+        match status {
+            Some(100..=199) => StatusClass::INFORMATIONAL,
+            Some(200..=299) => StatusClass::SUCCESS,
+            Some(300..=399) => StatusClass::REDIRECTION,
+            Some(400..=499) => StatusClass::CLIENT_ERROR,
+            Some(_) => StatusClass::SERVER_ERROR,
+            None => StatusClass::TRANSPORT_ERROR,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            StatusClass::INFORMATIONAL => "1xx",
+            StatusClass::SUCCESS => "2xx",
+            StatusClass::REDIRECTION => "3xx",
+            StatusClass::CLIENT_ERROR => "4xx",
+            StatusClass::SERVER_ERROR => "5xx",
+            StatusClass::TRANSPORT_ERROR => "transport_error",
+        }
+    }
+}
+
+/// Outcome of a single request attempt, `status` is `None` if no response was received.
+#[derive(Debug, Clone)]
+pub struct RequestMetrics<'a> {
+    pub method: &'a str,
+    pub path_template: &'a str,
+    pub status: Option<u16>,
+    pub status_class: StatusClass,
+    pub latency: Duration,
+    pub attempt: u32,
+}
+
+/// Called from the request pipeline after every request attempt, keep implementations cheap and non blocking.
+pub trait MetricsHook: Send + Sync + fmt::Debug {
+    fn on_request(&self, metrics: &RequestMetrics);
+
+    /// Number of requests of the given `EndpointClass` (e.g. `EVENTS`) currently waiting in the rate limiter.
+    fn on_queue_depth(&self, _class: &str, _depth: usize) {}
+}
+
+// upper bounds in seconds, the last bucket is +Inf
+pub const LATENCY_BUCKETS: [f64; 10] = [0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 25.0];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EndpointStats {
+    pub count: u64,
+    pub retries: u64,
+    pub latency_sum_sec: f64,
+    /// cumulative counts per `LATENCY_BUCKETS` entry, followed by +Inf
+    pub latency_buckets: Vec<u64>,
+}
+
+type EndpointKey = (String, String, StatusClass);
+
+/// Built-in `MetricsHook` keeping per endpoint counters and latency histograms in memory,
+/// rendered in the Prometheus text format via `to_prometheus()`.
+#[derive(Debug, Default)]
+pub struct InMemoryMetrics {
+    endpoints: Mutex<BTreeMap<EndpointKey, EndpointStats>>,
+    queue_depths: Mutex<BTreeMap<String, usize>>,
+}
+
+impl InMemoryMetrics {
+
+    pub fn new() -> InMemoryMetrics {
+        InMemoryMetrics::default()
+    }
+
+    /// Stats keyed by method, path template and status class.
+    pub fn snapshot(&self) -> BTreeMap<EndpointKey, EndpointStats> {
+        self.endpoints.lock().map(|endpoints| endpoints.clone()).unwrap_or_default()
+    }
+
+    /// Last reported rate limiter queue depth per endpoint class.
+    pub fn queue_depths(&self) -> BTreeMap<String, usize> {
+        self.queue_depths.lock().map(|queue_depths| queue_depths.clone()).unwrap_or_default()
+    }
+
+    pub fn to_prometheus(&self) -> String {
+
+        let mut out = String::new();
+        out.push_str("# TYPE ilert_client_requests_total counter\n");
+        out.push_str("# TYPE ilert_client_retries_total counter\n");
+        out.push_str("# TYPE ilert_client_request_duration_seconds histogram\n");
+        out.push_str("# TYPE ilert_client_queue_depth gauge\n");
+
+        for (class, depth) in self.queue_depths() {
+            out.push_str(format!("ilert_client_queue_depth{{class=\"{}\"}} {}\n", class, depth).as_str());
+        }
+
+        for ((method, path, status_class), stats) in self.snapshot() {
+            let labels = format!("method=\"{}\",path=\"{}\",status=\"{}\"", method, path, status_class.as_str());
+            out.push_str(format!("ilert_client_requests_total{{{}}} {}\n", labels, stats.count).as_str());
+            out.push_str(format!("ilert_client_retries_total{{{}}} {}\n", labels, stats.retries).as_str());
+            for (index, count) in stats.latency_buckets.iter().enumerate() {
+                let le = LATENCY_BUCKETS.get(index).map(|bound| bound.to_string()).unwrap_or_else(|| "+Inf".to_string());
+                out.push_str(format!("ilert_client_request_duration_seconds_bucket{{{},le=\"{}\"}} {}\n", labels, le, count).as_str());
+            }
+            out.push_str(format!("ilert_client_request_duration_seconds_sum{{{}}} {}\n", labels, stats.latency_sum_sec).as_str());
+            out.push_str(format!("ilert_client_request_duration_seconds_count{{{}}} {}\n", labels, stats.count).as_str());
+        }
+
+        out
+    }
+}
+
+impl MetricsHook for InMemoryMetrics {
+
+    fn on_request(&self, metrics: &RequestMetrics) {
+
+        let mut endpoints = match self.endpoints.lock() {
+            Ok(endpoints) => endpoints,
+            Err(_) => return,
+        };
+
+        let key = (metrics.method.to_string(), metrics.path_template.to_string(), metrics.status_class);
+        let stats = endpoints.entry(key).or_insert_with(|| EndpointStats {
+            latency_buckets: vec![0; LATENCY_BUCKETS.len() + 1],
+            ..EndpointStats::default()
+        });
+
+        let latency_sec = metrics.latency.as_secs_f64();
+        stats.count += 1;
+        stats.latency_sum_sec += latency_sec;
+        if metrics.attempt > 1 {
+            stats.retries += 1;
+        }
+
+        for (index, bound) in LATENCY_BUCKETS.iter().enumerate() {
+            if latency_sec <= *bound {
+                stats.latency_buckets[index] += 1;
+            }
+        }
+        stats.latency_buckets[LATENCY_BUCKETS.len()] += 1;
+    }
+
+    fn on_queue_depth(&self, class: &str, depth: usize) {
+        if let Ok(mut queue_depths) = self.queue_depths.lock() {
+            queue_depths.insert(class.to_string(), depth);
+        }
+    }
+}
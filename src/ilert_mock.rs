@@ -0,0 +1,614 @@
+//! In-process mock of the ilert API for offline tests, available with the `test-util` feature.
+//!
+//! Implements events, heartbeats, alerts, incidents, services, users and schedules on in-memory
+//! state under `/api` and records every request so tests can assert on what was sent.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+use base64::engine::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::{SecondsFormat, Utc};
+use serde_json::{json, Value};
+
+use crate::ilert::ILert;
+use crate::ilert_error::{ILertResult, ILertError};
+
+const API_PREFIX: &str = "/api";
+
+/// A request as received by the mock, `path` is relative to `/api`.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+}
+
+impl RecordedRequest {
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name.to_lowercase().as_str()).map(|value| value.as_str())
+    }
+
+    pub fn query_values(&self, key: &str) -> Vec<&str> {
+        self.query.iter()
+            .filter(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+
+    pub fn body_json(&self) -> Option<Value> {
+        self.body.as_ref().and_then(|body| serde_json::from_str(body.as_str()).ok())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Option<Value>,
+}
+
+impl MockResponse {
+
+    fn new(status: u16, body: Option<Value>) -> MockResponse {
+        MockResponse { status, headers: Vec::new(), body }
+    }
+
+    fn not_found() -> MockResponse {
+        MockResponse::new(404, Some(json!({"code": "NOT_FOUND", "message": "Resource not found."})))
+    }
+
+    fn bad_request(message: &str) -> MockResponse {
+        MockResponse::new(400, Some(json!({"code": "BAD_REQUEST", "message": message})))
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    next_id: i64,
+    basic_auth: Option<String>,
+    api_token: Option<String>,
+    alert_source_keys: HashMap<String, i64>,
+    heartbeats: HashMap<String, u64>,
+    events: Vec<Value>,
+    alerts: Vec<Value>,
+    incidents: Vec<Value>,
+    services: Vec<Value>,
+    users: Vec<Value>,
+    schedules: Vec<Value>,
+    requests: Vec<RecordedRequest>,
+    stubs: Vec<(String, String, MockResponse)>,
+}
+
+impl MockState {
+
+    fn next_id(&mut self) -> i64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn collection(&mut self, name: &str) -> Option<&mut Vec<Value>> {
+        match name {
+            "alerts" => Some(&mut self.alerts),
+            "incidents" => Some(&mut self.incidents),
+            "services" => Some(&mut self.services),
+            "users" => Some(&mut self.users),
+            "schedules" => Some(&mut self.schedules),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, name: &str, mut entity: Value) -> Value {
+        let id = self.next_id();
+        entity["id"] = json!(id);
+        if let Some(collection) = self.collection(name) {
+            collection.push(entity.clone());
+        }
+        entity
+    }
+
+    fn authorized(&self, request: &RecordedRequest) -> bool {
+
+        if self.basic_auth.is_none() && self.api_token.is_none() {
+            return true;
+        }
+
+        let authorization = request.header("authorization").unwrap_or_default();
+        self.basic_auth.as_ref().map(|expected| expected == authorization).unwrap_or(false)
+            || self.api_token.as_ref().map(|expected| expected == authorization).unwrap_or(false)
+    }
+}
+
+/// Mock ilert API listening on a random local port, stopped when dropped.
+#[derive(Debug)]
+pub struct MockILertServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl MockILertServer {
+
+    pub fn start() -> ILertResult<MockILertServer> {
+
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .map_err(|err| ILertError::new(format!("Failed to start mock server: {}.", err).as_str()))?;
+        let addr = listener.local_addr()
+            .map_err(|err| ILertError::new(format!("Failed to start mock server: {}.", err).as_str()))?;
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread_state = state.clone();
+        let thread_shutdown = shutdown.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if thread_shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let state = thread_state.clone();
+                    thread::spawn(move || handle_connection(stream, state));
+                }
+            }
+        });
+
+        Ok(MockILertServer { addr, state, shutdown })
+    }
+
+    /// Host to pass to `ILertBuilder::host()`, e.g. `http://127.0.0.1:41234`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Client pointing at this mock, without auth.
+    pub fn client(&self) -> ILertResult<ILert> {
+        ILert::builder()
+            .host(self.url().as_str())
+            .timeout_sec(10)
+            .build()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Management endpoints answer 401 unless these basic auth credentials are sent.
+    pub fn require_basic_auth(&self, user: &str, password: &str) -> &Self {
+        let credentials = BASE64.encode(format!("{}:{}", user, password));
+        self.state().basic_auth = Some(format!("Basic {}", credentials));
+        self
+    }
+
+    /// Management endpoints answer 401 unless this api token is sent.
+    pub fn require_api_token(&self, api_token: &str) -> &Self {
+        self.state().api_token = Some(format!("Bearer {}", api_token));
+        self
+    }
+
+    /// Events sent with this key are accepted, returns the id of the alert source.
+    pub fn add_alert_source(&self, api_key: &str) -> i64 {
+        let mut state = self.state();
+        let id = state.next_id();
+        state.alert_source_keys.insert(api_key.to_string(), id);
+        id
+    }
+
+    pub fn add_heartbeat(&self, key: &str) -> &Self {
+        self.state().heartbeats.insert(key.to_string(), 0);
+        self
+    }
+
+    /// Returns the stored entity with its assigned `id`.
+    pub fn add_alert(&self, alert: Value) -> Value {
+        self.state().insert("alerts", alert)
+    }
+
+    pub fn add_incident(&self, incident: Value) -> Value {
+        self.state().insert("incidents", incident)
+    }
+
+    pub fn add_service(&self, service: Value) -> Value {
+        self.state().insert("services", service)
+    }
+
+    pub fn add_user(&self, user: Value) -> Value {
+        self.state().insert("users", user)
+    }
+
+    /// Shifts returned by `/schedules/{id}/shifts` are read from the `shifts` field of the schedule.
+    pub fn add_schedule(&self, schedule: Value) -> Value {
+        self.state().insert("schedules", schedule)
+    }
+
+    /// Answers every request matching method and path (relative to `/api`) with the given response,
+    /// e.g. to simulate errors or rate limits. Stubs take precedence over the built-in endpoints.
+    pub fn stub(&self, method: &str, path: &str, status: u16, body: Option<Value>, headers: &[(&str, &str)]) -> &Self {
+        let response = MockResponse {
+            status,
+            headers: headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            body,
+        };
+        self.state().stubs.push((method.to_uppercase(), path.to_string(), response));
+        self
+    }
+
+    pub fn clear_stubs(&self) -> &Self {
+        self.state().stubs.clear();
+        self
+    }
+
+    pub fn alerts(&self) -> Vec<Value> {
+        self.state().alerts.clone()
+    }
+
+    pub fn incidents(&self) -> Vec<Value> {
+        self.state().incidents.clone()
+    }
+
+    pub fn services(&self) -> Vec<Value> {
+        self.state().services.clone()
+    }
+
+    /// All accepted event bodies in the order they were received.
+    pub fn events(&self) -> Vec<Value> {
+        self.state().events.clone()
+    }
+
+    pub fn heartbeat_pings(&self, key: &str) -> u64 {
+        self.state().heartbeats.get(key).copied().unwrap_or(0)
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state().requests.clone()
+    }
+
+    pub fn received(&self, method: &str, path: &str) -> Vec<RecordedRequest> {
+        self.state().requests.iter()
+            .filter(|request| request.method.eq_ignore_ascii_case(method) && request.path == path)
+            .cloned()
+            .collect()
+    }
+
+    pub fn reset_requests(&self) -> &Self {
+        self.state().requests.clear();
+        self
+    }
+
+    /// Panics unless at least one request with the given method and path was received.
+    pub fn assert_requested(&self, method: &str, path: &str) {
+        if self.received(method, path).is_empty() {
+            panic!("expected a {} {} request, received: {:?}", method, path, self.request_lines());
+        }
+    }
+
+    /// Panics unless exactly `times` requests with the given method and path were received.
+    pub fn assert_requested_times(&self, method: &str, path: &str, times: usize) {
+        let received = self.received(method, path).len();
+        if received != times {
+            panic!("expected {} {} {} time(s) but got {}, received: {:?}", method, path, times, received, self.request_lines());
+        }
+    }
+
+    pub fn assert_not_requested(&self, method: &str, path: &str) {
+        self.assert_requested_times(method, path, 0);
+    }
+
+    fn request_lines(&self) -> Vec<String> {
+        self.state().requests.iter()
+            .map(|request| format!("{} {}", request.method, request.path))
+            .collect()
+    }
+}
+
+impl Drop for MockILertServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // wakes up the accept loop so it can observe the shutdown flag
+        let _ = TcpStream::connect_timeout(&self.addr, Duration::from_millis(100));
+    }
+}
+
+fn handle_connection(stream: TcpStream, state: Arc<Mutex<MockState>>) {
+
+    let mut reader = match stream.try_clone() {
+        Ok(read_stream) => BufReader::new(read_stream),
+        Err(_) => return,
+    };
+    let mut stream = stream;
+
+    // keep-alive, serve requests until the client closes the connection
+    while let Some(request) = read_request(&mut reader) {
+
+        let response = {
+            let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            state.requests.push(request.clone());
+            route(&mut state, &request)
+        };
+
+        if write_response(&mut stream, &response).is_err() {
+            return;
+        }
+    }
+}
+
+fn read_request(reader: &mut BufReader<TcpStream>) -> Option<RecordedRequest> {
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).ok()? == 0 {
+        return None;
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?.to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let content_length = headers.get("content-length")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let body = if content_length > 0 {
+        let mut buf = vec![0; content_length];
+        reader.read_exact(&mut buf).ok()?;
+        Some(String::from_utf8_lossy(&buf).to_string())
+    } else {
+        None
+    };
+
+    let url = url::Url::parse(format!("http://mock{}", target).as_str()).ok()?;
+    let path = url.path().strip_prefix(API_PREFIX).unwrap_or(url.path()).to_string();
+    let query = url.query_pairs()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    Some(RecordedRequest { method, path, query, headers, body })
+}
+
+fn write_response(stream: &mut TcpStream, response: &MockResponse) -> std::io::Result<()> {
+
+    let body = response.body.as_ref().map(|body| body.to_string()).unwrap_or_default();
+
+    let mut head = format!("HTTP/1.1 {} {}\r\ncontent-length: {}\r\n",
+        response.status, reason(response.status), body.len());
+    if response.body.is_some() {
+        head.push_str("content-type: application/json\r\n");
+    }
+    for (name, value) in response.headers.iter() {
+        head.push_str(format!("{}: {}\r\n", name, value).as_str());
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
+
+fn route(state: &mut MockState, request: &RecordedRequest) -> MockResponse {
+
+    let mut response = match state.stubs.iter()
+        .find(|(method, path, _)| method.as_str() == request.method && path.as_str() == request.path) {
+        Some((_, _, response)) => response.clone(),
+        None => route_endpoint(state, request),
+    };
+
+    let request_id = format!("mock-{}", state.requests.len());
+    response.headers.push(("x-request-id".to_string(), request_id));
+    response
+}
+
+fn route_endpoint(state: &mut MockState, request: &RecordedRequest) -> MockResponse {
+
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let method = request.method.as_str();
+
+    match (method, segments.as_slice()) {
+        ("POST", ["events"]) => return handle_event(state, request),
+        ("GET", ["heartbeats", key]) | ("HEAD", ["heartbeats", key]) | ("POST", ["heartbeats", key]) => {
+            return match state.heartbeats.get_mut(*key) {
+                Some(pings) => {
+                    *pings += 1;
+                    MockResponse::new(202, None)
+                },
+                None => MockResponse::not_found(),
+            };
+        },
+        _ => {},
+    }
+
+    if !state.authorized(request) {
+        return MockResponse::new(401, Some(json!({"code": "UNAUTHORIZED", "message": "Missing or invalid credentials."})));
+    }
+
+    match (method, segments.as_slice()) {
+        ("GET", ["schedules", id, "shifts"]) => {
+            match find(&state.schedules, id) {
+                Some(schedule) => MockResponse::new(200, Some(schedule.get("shifts").cloned().unwrap_or_else(|| json!([])))),
+                None => MockResponse::not_found(),
+            }
+        },
+        ("PUT", ["alerts", id, "accept"]) => transition_alert(state, id, "ACCEPTED", "acceptedOn"),
+        ("PUT", ["alerts", id, "resolve"]) => transition_alert(state, id, "RESOLVED", "resolvedOn"),
+        ("GET", [name]) => match state.collection(name) {
+            Some(collection) => MockResponse::new(200, Some(Value::Array(list(collection, request)))),
+            None => MockResponse::not_found(),
+        },
+        ("GET", [name, id]) => match state.collection(name).and_then(|collection| find(collection, id)) {
+            Some(entity) => MockResponse::new(200, Some(entity)),
+            None => MockResponse::not_found(),
+        },
+        ("POST", [name]) if *name != "alerts" && state.collection(name).is_some() => {
+            match request.body_json() {
+                Some(entity) if entity.is_object() => MockResponse::new(201, Some(state.insert(name, entity))),
+                _ => MockResponse::bad_request("Request body must be a json object."),
+            }
+        },
+        ("PUT", [name, id]) => {
+            let update = match request.body_json() {
+                Some(update) if update.is_object() => update,
+                _ => return MockResponse::bad_request("Request body must be a json object."),
+            };
+            match state.collection(name).and_then(|collection| collection.iter_mut().find(|entity| matches_id(entity, id))) {
+                Some(entity) => {
+                    merge(entity, update);
+                    MockResponse::new(200, Some(entity.clone()))
+                },
+                None => MockResponse::not_found(),
+            }
+        },
+        ("DELETE", [name, id]) => match state.collection(name) {
+            Some(collection) => {
+                let len = collection.len();
+                collection.retain(|entity| !matches_id(entity, id));
+                if collection.len() < len {
+                    MockResponse::new(204, None)
+                } else {
+                    MockResponse::not_found()
+                }
+            },
+            None => MockResponse::not_found(),
+        },
+        _ => MockResponse::not_found(),
+    }
+}
+
+fn handle_event(state: &mut MockState, request: &RecordedRequest) -> MockResponse {
+
+    let event = match request.body_json() {
+        Some(event) if event.is_object() => event,
+        _ => return MockResponse::bad_request("Request body must be a json object."),
+    };
+
+    let alert_source_id = match event["apiKey"].as_str().and_then(|api_key| state.alert_source_keys.get(api_key)) {
+        Some(id) => *id,
+        None => return MockResponse::bad_request("Unknown apiKey."),
+    };
+
+    let alert_key = event["alertKey"].as_str().map(|alert_key| alert_key.to_string());
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    let open_alert = alert_key.as_ref().and_then(|alert_key| state.alerts.iter_mut().find(|alert| {
+        alert["alertSource"]["id"] == json!(alert_source_id)
+            && alert["alertKey"].as_str() == Some(alert_key.as_str())
+            && alert["status"] != json!("RESOLVED")
+    }));
+
+    match event["eventType"].as_str() {
+        Some("ALERT") => {
+            if open_alert.is_none() {
+                let alert = json!({
+                    "summary": event["summary"],
+                    "details": event["details"],
+                    "alertKey": alert_key,
+                    "status": "PENDING",
+                    "priority": event["priority"].as_str().unwrap_or("HIGH"),
+                    "alertSource": {"id": alert_source_id},
+                    "reportTime": now
+                });
+                state.insert("alerts", alert);
+            }
+        },
+        Some("ACCEPT") => {
+            if let Some(alert) = open_alert {
+                alert["status"] = json!("ACCEPTED");
+                alert["acceptedOn"] = json!(now);
+            }
+        },
+        Some("RESOLVE") => {
+            if let Some(alert) = open_alert {
+                alert["status"] = json!("RESOLVED");
+                alert["resolvedOn"] = json!(now);
+            }
+        },
+        Some("COMMENT") => {},
+        _ => return MockResponse::bad_request("Unknown eventType."),
+    }
+
+    state.events.push(event);
+    MockResponse::new(202, None)
+}
+
+fn transition_alert(state: &mut MockState, id: &str, status: &str, timestamp_field: &str) -> MockResponse {
+    match state.alerts.iter_mut().find(|alert| matches_id(alert, id)) {
+        Some(alert) => {
+            alert["status"] = json!(status);
+            alert[timestamp_field] = json!(Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true));
+            MockResponse::new(200, Some(alert.clone()))
+        },
+        None => MockResponse::not_found(),
+    }
+}
+
+fn matches_id(entity: &Value, id: &str) -> bool {
+    entity["id"].as_i64().map(|entity_id| entity_id.to_string() == id).unwrap_or(false)
+}
+
+fn find(collection: &[Value], id: &str) -> Option<Value> {
+    collection.iter().find(|entity| matches_id(entity, id)).cloned()
+}
+
+fn merge(entity: &mut Value, update: Value) {
+    if let (Some(entity), Value::Object(update)) = (entity.as_object_mut(), update) {
+        for (key, value) in update {
+            if key != "id" {
+                entity.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Applies `states` (alerts), `start-index` and `max-results` like the ilert API does.
+fn list(collection: &[Value], request: &RecordedRequest) -> Vec<Value> {
+
+    let states = request.query_values("states");
+    let start_index = request.query_values("start-index").first()
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let max_results = request.query_values("max-results").first()
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(usize::MAX);
+
+    collection.iter()
+        .filter(|entity| states.is_empty() || entity["status"].as_str().map(|status| states.contains(&status)).unwrap_or(false))
+        .skip(start_index)
+        .take(max_results)
+        .cloned()
+        .collect()
+}
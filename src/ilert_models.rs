@@ -0,0 +1,822 @@
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::ilert_builders::{AlertState, ILertPriority};
+use crate::ilert_error::{ILertResult, ILertError};
+use crate::ilert_filters::AuditLogEntityType;
+
+/// Reference to another ilert entity, e.g. the alert sources affected by a maintenance window.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct EntityRef {
+    pub id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl EntityRef {
+    pub fn new(id: i64) -> EntityRef {
+        EntityRef {
+            id,
+            name: None
+        }
+    }
+}
+
+/* ### ALERTS ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Alert {
+    pub id: i64,
+    pub summary: String,
+    #[serde(default)]
+    pub details: Option<String>,
+    #[serde(default)]
+    pub alert_key: Option<String>,
+    pub status: AlertState,
+    pub priority: ILertPriority,
+    pub report_time: DateTime<Utc>,
+    #[serde(default)]
+    pub accepted_on: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub resolved_on: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub alert_source: Option<EntityRef>,
+    #[serde(default)]
+    pub escalation_policy: Option<EntityRef>,
+    #[serde(default)]
+    pub team: Option<EntityRef>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertLogEntry {
+    pub id: i64,
+    pub alert_id: i64,
+    pub timestamp: DateTime<Utc>,
+    pub log_entry_type: String,
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+impl AlertLogEntry {
+
+    pub fn is_accept(&self) -> bool {
+        self.log_entry_type == "AlertAcceptedLogEntry"
+    }
+
+    pub fn is_resolve(&self) -> bool {
+        self.log_entry_type == "AlertResolvedLogEntry"
+    }
+}
+
+/* ### MAINTENANCE WINDOWS ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceWindow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub alert_sources: Vec<EntityRef>,
+    #[serde(default)]
+    pub services: Vec<EntityRef>,
+}
+
+impl MaintenanceWindow {
+
+    pub fn new(summary: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> MaintenanceWindow {
+        MaintenanceWindow {
+            id: None,
+            summary: summary.to_string(),
+            description: None,
+            start,
+            end,
+            timezone: None,
+            alert_sources: Vec::new(),
+            services: Vec::new()
+        }
+    }
+
+    /// Window starting right now and lasting for the given duration.
+    pub fn immediate(summary: &str, duration: std::time::Duration) -> ILertResult<MaintenanceWindow> {
+        let duration = Duration::from_std(duration)
+            .map_err(|err| ILertError::new(err.to_string().as_str()))?;
+        let start = Utc::now();
+        Ok(MaintenanceWindow::new(summary, start, start + duration))
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn timezone(mut self, timezone: &str) -> Self {
+        self.timezone = Some(timezone.to_string());
+        self
+    }
+
+    pub fn alert_source(mut self, id: i64) -> Self {
+        self.alert_sources.push(EntityRef::new(id));
+        self
+    }
+
+    pub fn service(mut self, id: i64) -> Self {
+        self.services.push(EntityRef::new(id));
+        self
+    }
+}
+
+/* ### STATUS PAGES ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum StatusPageVisibility {
+    PUBLIC,
+    PRIVATE,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    pub subdomain: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    pub visibility: StatusPageVisibility,
+    #[serde(default)]
+    pub services: Vec<EntityRef>,
+}
+
+impl StatusPage {
+
+    pub fn new(name: &str, subdomain: &str, visibility: StatusPageVisibility) -> StatusPage {
+        StatusPage {
+            id: None,
+            name: name.to_string(),
+            subdomain: subdomain.to_string(),
+            domain: None,
+            timezone: None,
+            visibility,
+            services: Vec::new()
+        }
+    }
+
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    pub fn timezone(mut self, timezone: &str) -> Self {
+        self.timezone = Some(timezone.to_string());
+        self
+    }
+
+    pub fn service(mut self, id: i64) -> Self {
+        self.add_service(id);
+        self
+    }
+
+    pub fn add_service(&mut self, id: i64) {
+        if !self.services.iter().any(|service| service.id == id) {
+            self.services.push(EntityRef::new(id));
+        }
+    }
+
+    pub fn remove_service(&mut self, id: i64) {
+        self.services.retain(|service| service.id != id);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusPageGroup {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    #[serde(default)]
+    pub services: Vec<EntityRef>,
+}
+
+impl StatusPageGroup {
+
+    pub fn new(name: &str) -> StatusPageGroup {
+        StatusPageGroup {
+            id: None,
+            name: name.to_string(),
+            services: Vec::new()
+        }
+    }
+
+    pub fn service(mut self, id: i64) -> Self {
+        self.services.push(EntityRef::new(id));
+        self
+    }
+}
+
+/* ### SUBSCRIBERS ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum SubscriberType {
+    EMAIL,
+    USER,
+    TEAM,
+}
+
+/// Subscriber of an incident, service or status page; `name` holds the address for `EMAIL` subscribers.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Subscriber {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub subscriber_type: SubscriberType,
+}
+
+impl Subscriber {
+
+    /// Email subscribers have no id until created, to remove one later look up its id by listing the subscribers.
+    pub fn email(email: &str) -> Subscriber {
+        Subscriber {
+            id: None,
+            name: Some(email.to_string()),
+            subscriber_type: SubscriberType::EMAIL
+        }
+    }
+
+    pub fn user(id: i64) -> Subscriber {
+        Subscriber {
+            id: Some(id),
+            name: None,
+            subscriber_type: SubscriberType::USER
+        }
+    }
+
+    pub fn team(id: i64) -> Subscriber {
+        Subscriber {
+            id: Some(id),
+            name: None,
+            subscriber_type: SubscriberType::TEAM
+        }
+    }
+}
+
+/* ### CONNECTORS ### */
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum ConnectorType {
+    #[serde(rename = "slack")]
+    SLACK,
+    #[serde(rename = "microsoft_teams")]
+    MICROSOFT_TEAMS,
+    #[serde(rename = "jira")]
+    JIRA,
+    #[serde(rename = "servicenow")]
+    SERVICENOW,
+    #[serde(rename = "zendesk")]
+    ZENDESK,
+    #[serde(rename = "github")]
+    GITHUB,
+    #[serde(rename = "discord")]
+    DISCORD,
+    #[serde(rename = "email")]
+    EMAIL,
+    #[serde(rename = "webhook")]
+    WEBHOOK,
+}
+
+/// Outbound integration, `params` carry the type specific configuration (e.g. webhook url or jira project).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Connector {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub connector_type: ConnectorType,
+    #[serde(default)]
+    pub params: serde_json::Value,
+}
+
+impl Connector {
+
+    pub fn new(name: &str, connector_type: ConnectorType, params: serde_json::Value) -> Connector {
+        Connector {
+            id: None,
+            name: name.to_string(),
+            connector_type,
+            params
+        }
+    }
+}
+
+/* ### ALERT ACTIONS ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum AlertActionTriggerMode {
+    AUTOMATIC,
+    MANUAL,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum AlertActionTriggerType {
+    AlertCreated,
+    AlertAssigned,
+    AlertAutoEscalated,
+    AlertAcknowledged,
+    AlertRaised,
+    AlertCommentAdded,
+    AlertEscalationEnded,
+    AlertResolved,
+    AlertAutoResolved,
+    AlertResponderAdded,
+    AlertResponderRemoved,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertAction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub alert_sources: Vec<EntityRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_id: Option<String>,
+    pub connector_type: ConnectorType,
+    pub trigger_mode: AlertActionTriggerMode,
+    #[serde(default)]
+    pub trigger_types: Vec<AlertActionTriggerType>,
+    #[serde(default)]
+    pub params: serde_json::Value,
+}
+
+impl AlertAction {
+
+    pub fn new(name: &str, connector: &Connector) -> AlertAction {
+        AlertAction {
+            id: None,
+            name: name.to_string(),
+            alert_sources: Vec::new(),
+            connector_id: connector.id.clone(),
+            connector_type: connector.connector_type,
+            trigger_mode: AlertActionTriggerMode::AUTOMATIC,
+            trigger_types: Vec::new(),
+            params: serde_json::Value::Null
+        }
+    }
+
+    pub fn alert_source(mut self, id: i64) -> Self {
+        self.alert_sources.push(EntityRef::new(id));
+        self
+    }
+
+    pub fn trigger_mode(mut self, trigger_mode: AlertActionTriggerMode) -> Self {
+        self.trigger_mode = trigger_mode;
+        self
+    }
+
+    pub fn trigger_type(mut self, trigger_type: AlertActionTriggerType) -> Self {
+        self.trigger_types.push(trigger_type);
+        self
+    }
+
+    pub fn params(mut self, params: serde_json::Value) -> Self {
+        self.params = params;
+        self
+    }
+}
+
+/* ### SUPPORT HOURS ### */
+
+mod hour_minute {
+
+    use chrono::NaiveTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    const FORMAT: &str = "%H:%M";
+
+    pub fn serialize<S: Serializer>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(time.format(FORMAT).to_string().as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveTime, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        NaiveTime::parse_from_str(raw.as_str(), FORMAT).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Time range of a single weekday, `end` before or equal to `start` continues into the next day.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct SupportHoursRange {
+    #[serde(with = "hour_minute")]
+    pub start: NaiveTime,
+    #[serde(with = "hour_minute")]
+    pub end: NaiveTime,
+}
+
+impl SupportHoursRange {
+
+    pub fn new(start: NaiveTime, end: NaiveTime) -> SupportHoursRange {
+        SupportHoursRange {
+            start,
+            end
+        }
+    }
+
+    fn wraps(&self) -> bool {
+        self.end <= self.start
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct SupportDays {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monday: Option<SupportHoursRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tuesday: Option<SupportHoursRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wednesday: Option<SupportHoursRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thursday: Option<SupportHoursRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friday: Option<SupportHoursRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saturday: Option<SupportHoursRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunday: Option<SupportHoursRange>,
+}
+
+impl SupportDays {
+
+    pub fn get(&self, day: Weekday) -> Option<&SupportHoursRange> {
+        match day {
+            Weekday::Mon => self.monday.as_ref(),
+            Weekday::Tue => self.tuesday.as_ref(),
+            Weekday::Wed => self.wednesday.as_ref(),
+            Weekday::Thu => self.thursday.as_ref(),
+            Weekday::Fri => self.friday.as_ref(),
+            Weekday::Sat => self.saturday.as_ref(),
+            Weekday::Sun => self.sunday.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, day: Weekday, range: Option<SupportHoursRange>) {
+        match day {
+            Weekday::Mon => self.monday = range,
+            Weekday::Tue => self.tuesday = range,
+            Weekday::Wed => self.wednesday = range,
+            Weekday::Thu => self.thursday = range,
+            Weekday::Fri => self.friday = range,
+            Weekday::Sat => self.saturday = range,
+            Weekday::Sun => self.sunday = range,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SupportHours {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    pub timezone: String,
+    #[serde(default)]
+    pub support_days: SupportDays,
+}
+
+impl SupportHours {
+
+    pub fn new(name: &str, timezone: &str) -> SupportHours {
+        SupportHours {
+            id: None,
+            name: name.to_string(),
+            timezone: timezone.to_string(),
+            support_days: SupportDays::default()
+        }
+    }
+
+    pub fn day(mut self, day: Weekday, start: NaiveTime, end: NaiveTime) -> Self {
+        self.support_days.set(day, Some(SupportHoursRange::new(start, end)));
+        self
+    }
+
+    /// Evaluates locally whether the given instant falls into these support hours,
+    /// fails if `timezone` is not a known IANA timezone name.
+    pub fn contains(&self, instant: &DateTime<Utc>) -> ILertResult<bool> {
+
+        let timezone: Tz = self.timezone.parse()
+            .map_err(|_| ILertError::new(format!("Unknown timezone '{}'.", self.timezone).as_str()))?;
+
+        let local = instant.with_timezone(&timezone);
+        let time = local.time();
+
+        if let Some(range) = self.support_days.get(local.weekday()) {
+            if range.wraps() {
+                if time >= range.start {
+                    return Ok(true);
+                }
+            } else if time >= range.start && time < range.end {
+                return Ok(true);
+            }
+        }
+
+        // an overnight range of the previous day may still be running
+        if let Some(range) = self.support_days.get(local.weekday().pred()) {
+            if range.wraps() && time < range.end {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Keeps the given priority inside support hours and downgrades to `ILertPriority::LOW` outside of them.
+    pub fn priority_at(&self, instant: &DateTime<Utc>, priority: ILertPriority) -> ILertResult<ILertPriority> {
+        if self.contains(instant)? {
+            Ok(priority)
+        } else {
+            Ok(ILertPriority::LOW)
+        }
+    }
+}
+
+/* ### METRICS ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum MetricAggregationType {
+    AVG,
+    SUM,
+    MIN,
+    MAX,
+    LAST,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum MetricDisplayType {
+    GRAPH,
+    SINGLE,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Metric {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub aggregation_type: MetricAggregationType,
+    pub display_type: MetricDisplayType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_source: Option<EntityRef>,
+}
+
+impl Metric {
+
+    pub fn new(name: &str, aggregation_type: MetricAggregationType, display_type: MetricDisplayType) -> Metric {
+        Metric {
+            id: None,
+            name: name.to_string(),
+            description: None,
+            aggregation_type,
+            display_type,
+            unit_label: None,
+            data_source: None
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn unit_label(mut self, unit_label: &str) -> Self {
+        self.unit_label = Some(unit_label.to_string());
+        self
+    }
+
+    pub fn data_source(mut self, id: i64) -> Self {
+        self.data_source = Some(EntityRef::new(id));
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct MetricDataPoint {
+    pub timestamp: DateTime<Utc>,
+    pub value: f64,
+}
+
+impl MetricDataPoint {
+
+    pub fn new(timestamp: DateTime<Utc>, value: f64) -> MetricDataPoint {
+        MetricDataPoint {
+            timestamp,
+            value
+        }
+    }
+
+    pub fn now(value: f64) -> MetricDataPoint {
+        MetricDataPoint::new(Utc::now(), value)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum MetricDataSourceType {
+    DATADOG,
+    PROMETHEUS,
+}
+
+/// External source metrics are pulled from, `params` carry the type specific configuration.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetricDataSource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub data_source_type: MetricDataSourceType,
+    #[serde(default)]
+    pub params: serde_json::Value,
+}
+
+impl MetricDataSource {
+
+    pub fn new(name: &str, data_source_type: MetricDataSourceType, params: serde_json::Value) -> MetricDataSource {
+        MetricDataSource {
+            id: None,
+            name: name.to_string(),
+            data_source_type,
+            params
+        }
+    }
+}
+
+/* ### DEPLOYMENTS ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentEvent {
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_details: Option<serde_json::Value>,
+}
+
+impl DeploymentEvent {
+
+    pub fn new(summary: &str) -> DeploymentEvent {
+        DeploymentEvent {
+            summary: summary.to_string(),
+            commit: None,
+            author: None,
+            repository_url: None,
+            custom_details: None
+        }
+    }
+
+    pub fn commit(mut self, commit: &str) -> Self {
+        self.commit = Some(commit.to_string());
+        self
+    }
+
+    pub fn author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn repository_url(mut self, repository_url: &str) -> Self {
+        self.repository_url = Some(repository_url.to_string());
+        self
+    }
+
+    pub fn custom_details(mut self, custom_details: serde_json::Value) -> Self {
+        self.custom_details = Some(custom_details);
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum DeploymentPipelineIntegrationType {
+    API,
+    GITHUB,
+    GITLAB,
+}
+
+/// `integration_key` is assigned by ilert on creation and used to post `DeploymentEvent`s.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentPipeline {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    pub integration_type: DeploymentPipelineIntegrationType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_key: Option<String>,
+    #[serde(default)]
+    pub params: serde_json::Value,
+}
+
+impl DeploymentPipeline {
+
+    pub fn new(name: &str, integration_type: DeploymentPipelineIntegrationType) -> DeploymentPipeline {
+        DeploymentPipeline {
+            id: None,
+            name: name.to_string(),
+            integration_type,
+            integration_key: None,
+            params: serde_json::Value::Null
+        }
+    }
+}
+
+/* ### CALL FLOWS ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PhoneNumber {
+    pub region_code: String,
+    pub number: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFlowNumber {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub phone_number: PhoneNumber,
+    #[serde(default)]
+    pub supported_languages: Vec<String>,
+}
+
+/// Call routing configuration, `root_node` holds the node tree as returned by the api.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFlow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_number: Option<CallFlowNumber>,
+    #[serde(default)]
+    pub root_node: serde_json::Value,
+}
+
+impl CallFlow {
+
+    pub fn new(name: &str, root_node: serde_json::Value) -> CallFlow {
+        CallFlow {
+            id: None,
+            name: name.to_string(),
+            language: None,
+            assigned_number: None,
+            root_node
+        }
+    }
+
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+}
+
+/* ### AUDIT LOGS ### */
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntry {
+    pub id: i64,
+    pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub actor: Option<EntityRef>,
+    pub action: String,
+    pub entity_type: AuditLogEntityType,
+    #[serde(default)]
+    pub entity_id: Option<String>,
+    #[serde(default)]
+    pub entity_name: Option<String>,
+    #[serde(default)]
+    pub changes: serde_json::Value,
+}
@@ -0,0 +1,311 @@
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::ilert_error::{ILertResult, ILertError};
+use crate::ilert_metrics::MetricsHook;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EndpointClass {
+    EVENTS,
+    MANAGEMENT,
+}
+
+impl EndpointClass {
+
+    /// Event, heartbeat and deployment event ingestion is `EVENTS`, everything else `MANAGEMENT`.
+    pub fn from_path(path_template: &str) -> EndpointClass {
+        if path_template == "/events" || path_template.starts_with("/events/")
+            || path_template.starts_with("/heartbeats/") || path_template.starts_with("/deployment-events/") {
+            EndpointClass::EVENTS
+        } else {
+            EndpointClass::MANAGEMENT
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            EndpointClass::EVENTS => "EVENTS",
+            EndpointClass::MANAGEMENT => "MANAGEMENT",
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RateLimitMode {
+    /// Wait up to the given duration for a token (and for `Retry-After` on 429 responses), a 429 whose
+    /// `Retry-After` exceeds it is returned as result.
+    WAIT(Duration),
+    /// Return an error right away if no token is available.
+    FAIL_FAST,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitConfig {
+    pub events_per_sec: f64,
+    pub events_burst: u32,
+    pub management_per_sec: f64,
+    pub management_burst: u32,
+    pub mode: RateLimitMode,
+    /// Retries of requests answered with 429, only applied in `WAIT` mode.
+    pub max_retries_on_429: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        RateLimitConfig {
+            events_per_sec: 50.0,
+            events_burst: 100,
+            management_per_sec: 5.0,
+            management_burst: 10,
+            mode: RateLimitMode::WAIT(Duration::from_secs(30)),
+            max_retries_on_429: 3,
+        }
+    }
+}
+
+impl RateLimitConfig {
+
+    pub fn new() -> RateLimitConfig {
+        RateLimitConfig::default()
+    }
+
+    pub fn events(mut self, per_sec: f64, burst: u32) -> Self {
+        self.events_per_sec = per_sec;
+        self.events_burst = burst;
+        self
+    }
+
+    pub fn management(mut self, per_sec: f64, burst: u32) -> Self {
+        self.management_per_sec = per_sec;
+        self.management_burst = burst;
+        self
+    }
+
+    pub fn mode(mut self, mode: RateLimitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn max_retries_on_429(mut self, max_retries: u32) -> Self {
+        self.max_retries_on_429 = max_retries;
+        self
+    }
+}
+
+// adaptive tightening: halve the rate on 429, recover additively on success, never below this share of the configured rate
+const MIN_RATE_FACTOR: f64 = 0.1;
+const RECOVERY_FACTOR: f64 = 0.1;
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+struct TokenBucket {
+    configured_rate: f64,
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    last_refill: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl TokenBucket {
+
+    fn new(rate: f64, burst: u32) -> TokenBucket {
+        let rate = rate.max(f64::MIN_POSITIVE);
+        let capacity = f64::from(burst.max(1));
+        TokenBucket {
+            configured_rate: rate,
+            rate,
+            capacity,
+            tokens: capacity,
+            last_refill: Instant::now(),
+            blocked_until: None,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Takes a token or returns how long to wait for the next one.
+    fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+
+        if let Some(blocked_until) = self.blocked_until {
+            if blocked_until > now {
+                return Err(blocked_until - now);
+            }
+            self.blocked_until = None;
+        }
+
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+        }
+    }
+}
+
+/// Token bucket rate limiter with separate budgets for event and management traffic.
+/// Waiting event requests take precedence, management requests hold back until they are served.
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    events: Mutex<TokenBucket>,
+    management: Mutex<TokenBucket>,
+    pub(crate) events_waiting: AtomicUsize,
+    management_waiting: AtomicUsize,
+    metrics_hook: Option<Arc<dyn MetricsHook>>,
+}
+
+impl RateLimiter {
+
+    pub fn new(config: RateLimitConfig) -> RateLimiter {
+        RateLimiter {
+            config,
+            events: Mutex::new(TokenBucket::new(config.events_per_sec, config.events_burst)),
+            management: Mutex::new(TokenBucket::new(config.management_per_sec, config.management_burst)),
+            events_waiting: AtomicUsize::new(0),
+            management_waiting: AtomicUsize::new(0),
+            metrics_hook: None,
+        }
+    }
+
+    /// Reports the number of waiting requests per class via `MetricsHook::on_queue_depth()`.
+    pub fn metrics_hook(mut self, metrics_hook: Arc<dyn MetricsHook>) -> Self {
+        self.metrics_hook = Some(metrics_hook);
+        self
+    }
+
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    /// Number of requests of the given class currently waiting for a token.
+    pub fn queue_depth(&self, class: EndpointClass) -> usize {
+        self.waiting(class).load(Ordering::SeqCst)
+    }
+
+    fn waiting(&self, class: EndpointClass) -> &AtomicUsize {
+        match class {
+            EndpointClass::EVENTS => &self.events_waiting,
+            EndpointClass::MANAGEMENT => &self.management_waiting,
+        }
+    }
+
+    fn enter_queue(&self, class: EndpointClass) {
+        let depth = self.waiting(class).fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(metrics_hook) = &self.metrics_hook {
+            metrics_hook.on_queue_depth(class.as_str(), depth);
+        }
+    }
+
+    fn leave_queue(&self, class: EndpointClass) {
+        let depth = self.waiting(class).fetch_sub(1, Ordering::SeqCst) - 1;
+        if let Some(metrics_hook) = &self.metrics_hook {
+            metrics_hook.on_queue_depth(class.as_str(), depth);
+        }
+    }
+
+    fn bucket(&self, class: EndpointClass) -> &Mutex<TokenBucket> {
+        match class {
+            EndpointClass::EVENTS => &self.events,
+            EndpointClass::MANAGEMENT => &self.management,
+        }
+    }
+
+    fn max_wait(&self) -> Option<Duration> {
+        match self.config.mode {
+            RateLimitMode::WAIT(max_wait) => Some(max_wait),
+            RateLimitMode::FAIL_FAST => None,
+        }
+    }
+
+    /// Blocks until a request of the given class may be sent, or fails according to the configured mode.
+    pub fn acquire(&self, class: EndpointClass) -> ILertResult<()> {
+
+        let started = Instant::now();
+        let mut queued = false;
+
+        let result = loop {
+            let now = Instant::now();
+
+            // fail fast callers only fail on an empty bucket, they do not yield to waiting events
+            let yield_to_events = class == EndpointClass::MANAGEMENT && self.max_wait().is_some()
+                && self.events_waiting.load(Ordering::SeqCst) > 0;
+
+            let wait = if yield_to_events {
+                Err(Duration::from_millis(5))
+            } else {
+                match self.bucket(class).lock() {
+                    Ok(mut bucket) => bucket.try_take(now),
+                    Err(_) => break Err(ILertError::new("Rate limiter state is poisoned.")),
+                }
+            };
+
+            let wait = match wait {
+                Ok(()) => break Ok(()),
+                Err(wait) => wait,
+            };
+
+            let remaining = self.max_wait()
+                .map(|max_wait| max_wait.saturating_sub(now.duration_since(started)))
+                .unwrap_or_default();
+
+            if wait > remaining {
+                break Err(ILertError::new(format!("Rate limit exceeded for {} requests, next slot in {}ms.",
+                    class.as_str(), wait.as_millis()).as_str()));
+            }
+
+            if !queued {
+                self.enter_queue(class);
+                queued = true;
+            }
+
+            thread::sleep(wait);
+        };
+
+        if queued {
+            self.leave_queue(class);
+        }
+
+        result
+    }
+
+    /// Tightens the budget after a 429, no further requests of this class are sent before `retry_after`.
+    pub fn on_rate_limited(&self, class: EndpointClass, retry_after: Option<Duration>) {
+        if let Ok(mut bucket) = self.bucket(class).lock() {
+            let now = Instant::now();
+            bucket.refill(now);
+            bucket.rate = (bucket.rate / 2.0).max(bucket.configured_rate * MIN_RATE_FACTOR);
+            bucket.tokens = 0.0;
+            bucket.blocked_until = Some(now + retry_after.unwrap_or(DEFAULT_RETRY_AFTER));
+        }
+    }
+
+    /// Drains the bucket when the server reports its quota is used up, e.g. via `x-ratelimit-remaining: 0`.
+    pub fn on_quota_exhausted(&self, class: EndpointClass) {
+        if let Ok(mut bucket) = self.bucket(class).lock() {
+            bucket.refill(Instant::now());
+            bucket.tokens = 0.0;
+        }
+    }
+
+    pub fn on_success(&self, class: EndpointClass) {
+        if let Ok(mut bucket) = self.bucket(class).lock() {
+            if bucket.rate < bucket.configured_rate {
+                bucket.rate = (bucket.rate + bucket.configured_rate * RECOVERY_FACTOR).min(bucket.configured_rate);
+            }
+        }
+    }
+
+    /// Current (possibly tightened) rate of the given class in requests per second.
+    pub fn current_rate(&self, class: EndpointClass) -> f64 {
+        self.bucket(class).lock().map(|bucket| bucket.rate).unwrap_or(0.0)
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::ilert_models::{Alert, EntityRef};
+
+/// Single row of a report, either returned by the `/reports` endpoints
+/// or computed locally via `aggregate_by_alert_source()` / `aggregate_by_team()`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportItem {
+    #[serde(default)]
+    pub alert_source: Option<EntityRef>,
+    #[serde(default)]
+    pub team: Option<EntityRef>,
+    #[serde(default)]
+    pub interval_start: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub alert_count: u64,
+    #[serde(default)]
+    pub accepted_count: u64,
+    #[serde(default)]
+    pub resolved_count: u64,
+    #[serde(default)]
+    pub mtta_seconds: Option<f64>,
+    #[serde(default)]
+    pub mttr_seconds: Option<f64>,
+    #[serde(default)]
+    pub notification_count: Option<u64>,
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
+
+pub(crate) fn seconds_between(from: &DateTime<Utc>, to: &DateTime<Utc>) -> f64 {
+    (*to - *from).num_milliseconds() as f64 / 1000.0
+}
+
+pub(crate) fn acknowledgment_seconds(alert: &Alert) -> Option<f64> {
+    alert.accepted_on.as_ref().map(|at| seconds_between(&alert.report_time, at))
+}
+
+pub(crate) fn resolution_seconds(alert: &Alert) -> Option<f64> {
+    alert.resolved_on.as_ref().map(|at| seconds_between(&alert.report_time, at))
+}
+
+/// Computes alert counts and mean time to acknowledge/resolve from alerts fetched
+/// via `AlertGetApiResource::alerts()`, one item per alert source (alerts without source are grouped together).
+pub fn aggregate_by_alert_source(alerts: &[Alert]) -> Vec<ReportItem> {
+    aggregate_by(alerts, |alert| alert.alert_source.as_ref())
+        .into_iter()
+        .map(|(alert_source, item)| ReportItem { alert_source, ..item })
+        .collect()
+}
+
+/// Same as `aggregate_by_alert_source()`, one item per team (alerts without team are grouped together).
+pub fn aggregate_by_team(alerts: &[Alert]) -> Vec<ReportItem> {
+    aggregate_by(alerts, |alert| alert.team.as_ref())
+        .into_iter()
+        .map(|(team, item)| ReportItem { team, ..item })
+        .collect()
+}
+
+fn aggregate_by<F>(alerts: &[Alert], key: F) -> Vec<(Option<EntityRef>, ReportItem)>
+    where F: Fn(&Alert) -> Option<&EntityRef> {
+
+    let mut groups: BTreeMap<Option<i64>, Vec<&Alert>> = BTreeMap::new();
+    for alert in alerts {
+        groups.entry(key(alert).map(|entity| entity.id))
+            .or_default()
+            .push(alert);
+    }
+
+    groups.into_values()
+        .map(|group| {
+            let ack_times: Vec<f64> = group.iter().filter_map(|alert| acknowledgment_seconds(alert)).collect();
+            let resolve_times: Vec<f64> = group.iter().filter_map(|alert| resolution_seconds(alert)).collect();
+
+            (key(group[0]).cloned(), ReportItem {
+                alert_count: group.len() as u64,
+                accepted_count: ack_times.len() as u64,
+                resolved_count: resolve_times.len() as u64,
+                mtta_seconds: mean(&ack_times),
+                mttr_seconds: mean(&resolve_times),
+                ..ReportItem::default()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+use http::header::HeaderMap;
+use http::{Method, StatusCode};
+#[cfg(feature = "reqwest")]
+use reqwest::blocking::Client;
+
+use crate::ilert_error::{ILertResult, ILertError};
+use crate::ilert_redact::{redact_url, RedactedBody, RedactedHeaders};
+
+/// Fully prepared request, `url` already carries the query parameters and `headers` the authorization.
+#[derive(Clone)]
+pub struct TransportRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<String>,
+}
+
+impl fmt::Debug for TransportRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransportRequest")
+            .field("method", &self.method)
+            .field("url", &redact_url(self.url.as_str()))
+            .field("headers", &RedactedHeaders(&self.headers))
+            .field("body", &RedactedBody(&self.body))
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Option<String>,
+}
+
+impl TransportResponse {
+
+    pub fn new(status: StatusCode, headers: HeaderMap, body: Option<String>) -> TransportResponse {
+        TransportResponse { status, headers, body }
+    }
+}
+
+impl fmt::Debug for TransportResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransportResponse")
+            .field("status", &self.status)
+            .field("headers", &RedactedHeaders(&self.headers))
+            .field("body", &RedactedBody(&self.body))
+            .finish()
+    }
+}
+
+/// HTTP backend every request builder sends through, implement it to plug in another
+/// HTTP client or an in-memory fake and pass it to `ILertBuilder::transport()`.
+/// Only transport failures are errors, any received response (incl. 4xx/5xx) is `Ok`.
+pub trait HttpTransport: Send + Sync + fmt::Debug {
+    fn execute(&self, request: TransportRequest) -> ILertResult<TransportResponse>;
+}
+
+/// Default transport based on a blocking `reqwest` client, requires the (default) `reqwest` feature.
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+
+    pub fn new(client: Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+}
+
+// reqwest errors carry the full url which may contain keys, e.g. for heartbeats
+#[cfg(feature = "reqwest")]
+pub(crate) fn request_error(err: reqwest::Error, url: &str) -> ILertError {
+    ILertError::new(format!("{} ({})", err.without_url(), redact_url(url)).as_str())
+}
+
+#[cfg(feature = "reqwest")]
+impl HttpTransport for ReqwestTransport {
+    fn execute(&self, request: TransportRequest) -> ILertResult<TransportResponse> {
+
+        let mut request_builder = self.client
+            .request(request.method, request.url.as_str())
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            request_builder = request_builder.body(body);
+        }
+
+        let response = request_builder.send()
+            .map_err(|err| request_error(err, request.url.as_str()))?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        Ok(TransportResponse::new(status, headers, response.text().ok()))
+    }
+}